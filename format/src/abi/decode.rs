@@ -0,0 +1,379 @@
+use super::param_type::sequence_head_size;
+use super::{ParamType, Value};
+use crate::types::{Address, Data, FixedSizeData, Quantity};
+use crate::{Error, Result};
+
+const WORD: usize = 32;
+// Decoded output is limited to this multiple of the input length.
+const MAX_OUTPUT_FACTOR: usize = 4;
+
+pub(crate) fn decode_params(types: &[ParamType], data: &[u8]) -> Result<Vec<Value>> {
+    let mut budget = Budget::new(data);
+    decode_sequence(
+        types.iter(),
+        sequence_head_size(types),
+        data,
+        0,
+        &mut budget,
+    )
+}
+
+/// Decodes a value that was written into an event topic.
+///
+/// Indexed dynamic values are only stored as the hash of their encoding, so they are returned as
+/// fixed bytes.
+pub(crate) fn decode_topic(kind: &ParamType, topic: &[u8; WORD]) -> Result<Value> {
+    if kind.is_dynamic() || matches!(kind, ParamType::FixedArray(..) | ParamType::Tuple(_)) {
+        return Ok(Value::FixedBytes(Data::from(topic.as_slice())));
+    }
+
+    decode_value(kind, topic, 0, &mut Budget::new(topic))
+}
+
+// Dynamic values are located through offsets, so any number of them can point at the same
+// encoding and the decoded size isn't bounded by the input length. Every decoded value is charged
+// a word and bytes and strings additionally their length.
+struct Budget(usize);
+
+impl Budget {
+    fn new(data: &[u8]) -> Self {
+        Self(data.len().saturating_mul(MAX_OUTPUT_FACTOR))
+    }
+
+    fn charge(&mut self, size: usize) -> Result<()> {
+        self.0 = self
+            .0
+            .checked_sub(size)
+            .ok_or_else(|| invalid("decoded data too large"))?;
+
+        Ok(())
+    }
+}
+
+// `head_size` is the combined head size of `types`, `None` if it overflows. It is checked against
+// the data before decoding so corrupt lengths and huge fixed size arrays fail without allocating
+// or iterating. Every type has a non-zero head size, so the number of types is bounded by the
+// length of the data as well.
+fn decode_sequence<'a, I>(
+    types: I,
+    head_size: Option<usize>,
+    data: &[u8],
+    base: usize,
+    budget: &mut Budget,
+) -> Result<Vec<Value>>
+where
+    I: ExactSizeIterator<Item = &'a ParamType>,
+{
+    let head_end = head_size.and_then(|size| base.checked_add(size));
+    if !matches!(head_end, Some(end) if end <= data.len()) {
+        return Err(invalid("data too short"));
+    }
+
+    let mut values = Vec::with_capacity(types.len());
+    let mut pos = base;
+
+    for kind in types {
+        let value = if kind.is_dynamic() {
+            let offset = read_usize(data, pos)?;
+            let at = base
+                .checked_add(offset)
+                .ok_or_else(|| invalid("offset out of bounds"))?;
+            decode_value(kind, data, at, budget)?
+        } else {
+            decode_value(kind, data, pos, budget)?
+        };
+
+        values.push(value);
+        // Can't overflow since the sum of the head sizes fits into the data.
+        pos += kind.head_size().unwrap();
+    }
+
+    Ok(values)
+}
+
+fn decode_array(
+    inner: &ParamType,
+    len: usize,
+    data: &[u8],
+    at: usize,
+    budget: &mut Budget,
+) -> Result<Vec<Value>> {
+    let head_size = inner.head_size().and_then(|size| size.checked_mul(len));
+
+    decode_sequence(std::iter::repeat_n(inner, len), head_size, data, at, budget)
+}
+
+fn decode_value(kind: &ParamType, data: &[u8], at: usize, budget: &mut Budget) -> Result<Value> {
+    budget.charge(WORD)?;
+
+    match kind {
+        ParamType::Address => {
+            let word = read_word(data, at)?;
+            check_zero(&word[..12], "dirty address padding")?;
+            Ok(Value::Address(Address::try_from(&word[12..])?))
+        }
+        ParamType::Bool => match read_word(data, at)? {
+            word if word[..31].iter().all(|b| *b == 0) && word[31] <= 1 => {
+                Ok(Value::Bool(word[31] == 1))
+            }
+            _ => Err(invalid("invalid bool")),
+        },
+        ParamType::Int(_) => Ok(Value::Int(FixedSizeData::try_from(read_word(data, at)?)?)),
+        ParamType::Uint(_) => {
+            let word = read_word(data, at)?;
+            Ok(Value::Uint(match word.iter().position(|b| *b != 0) {
+                Some(idx) => Quantity::from(&word[idx..]),
+                None => Quantity::default(),
+            }))
+        }
+        ParamType::FixedBytes(size) => Ok(Value::FixedBytes(Data::from(
+            &read_word(data, at)?[..*size],
+        ))),
+        ParamType::Function => Ok(Value::FixedBytes(Data::from(&read_word(data, at)?[..24]))),
+        ParamType::Bytes => Ok(Value::Bytes(Data::from(read_bytes(data, at, budget)?))),
+        ParamType::String => Ok(Value::String(
+            String::from_utf8_lossy(read_bytes(data, at, budget)?).into_owned(),
+        )),
+        ParamType::Array(inner) => {
+            let len = read_usize(data, at)?;
+            decode_array(inner, len, data, at + WORD, budget).map(Value::Array)
+        }
+        ParamType::FixedArray(inner, size) => {
+            decode_array(inner, *size, data, at, budget).map(Value::Array)
+        }
+        ParamType::Tuple(types) => {
+            decode_sequence(types.iter(), sequence_head_size(types), data, at, budget)
+                .map(Value::Tuple)
+        }
+    }
+}
+
+fn read_word(data: &[u8], at: usize) -> Result<&[u8]> {
+    at.checked_add(WORD)
+        .and_then(|end| data.get(at..end))
+        .ok_or_else(|| invalid("data too short"))
+}
+
+fn read_usize(data: &[u8], at: usize) -> Result<usize> {
+    let word = read_word(data, at)?;
+    check_zero(&word[..24], "offset or length out of bounds")?;
+    usize::try_from(u64::from_be_bytes(word[24..].try_into().unwrap()))
+        .map_err(|_| invalid("offset or length out of bounds"))
+}
+
+fn read_bytes<'a>(data: &'a [u8], at: usize, budget: &mut Budget) -> Result<&'a [u8]> {
+    let len = read_usize(data, at)?;
+    let start = at + WORD;
+    let bytes = start
+        .checked_add(len)
+        .and_then(|end| data.get(start..end))
+        .ok_or_else(|| invalid("data too short"))?;
+    budget.charge(len)?;
+
+    Ok(bytes)
+}
+
+fn check_zero(buf: &[u8], msg: &str) -> Result<()> {
+    if buf.iter().any(|b| *b != 0) {
+        return Err(invalid(msg));
+    }
+
+    Ok(())
+}
+
+fn invalid(msg: &str) -> Error {
+    Error::InvalidAbiData(msg.to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_params, decode_topic};
+    use crate::abi::{ParamType, Value};
+    use crate::types::{Address, Data, Quantity};
+    use hex_literal::hex;
+
+    fn parse(kind: &str) -> ParamType {
+        ParamType::parse(kind, Vec::new()).unwrap()
+    }
+
+    #[test]
+    fn test_static() {
+        let data = hex!(
+            "0000000000000000000000000101010101010101010101010101010101010101"
+            "0000000000000000000000000000000000000000000000000000000000000001"
+            "0000000000000000000000000000000000000000000000000000000000004200"
+            "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"
+        );
+
+        let values = decode_params(
+            &[
+                parse("address"),
+                parse("bool"),
+                parse("uint256"),
+                parse("int8"),
+            ],
+            &data,
+        )
+        .unwrap();
+
+        assert_eq!(
+            values,
+            vec![
+                Value::Address(Address::from([1; 20])),
+                Value::Bool(true),
+                Value::Uint(Quantity::from([0x42, 0x00])),
+                Value::Int([0xff; 32].into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_dynamic() {
+        // (uint256, string, uint256[]) = (7, "skar", [1, 2])
+        let data = hex!(
+            "0000000000000000000000000000000000000000000000000000000000000007"
+            "0000000000000000000000000000000000000000000000000000000000000060"
+            "00000000000000000000000000000000000000000000000000000000000000a0"
+            "0000000000000000000000000000000000000000000000000000000000000004"
+            "736b617200000000000000000000000000000000000000000000000000000000"
+            "0000000000000000000000000000000000000000000000000000000000000002"
+            "0000000000000000000000000000000000000000000000000000000000000001"
+            "0000000000000000000000000000000000000000000000000000000000000002"
+        );
+
+        let values = decode_params(
+            &[parse("uint256"), parse("string"), parse("uint256[]")],
+            &data,
+        )
+        .unwrap();
+
+        assert_eq!(
+            values,
+            vec![
+                Value::Uint(Quantity::from([7])),
+                Value::String("skar".to_owned()),
+                Value::Array(vec![
+                    Value::Uint(Quantity::from([1])),
+                    Value::Uint(Quantity::from([2])),
+                ]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_nested_tuple() {
+        // ((bytes, bool), bytes2[2]) = ((0x1234, false), [0xaaaa, 0xbbbb])
+        let kind = ParamType::parse("tuple", vec![parse("bytes"), parse("bool")]).unwrap();
+        let data = hex!(
+            "0000000000000000000000000000000000000000000000000000000000000060"
+            "aaaa000000000000000000000000000000000000000000000000000000000000"
+            "bbbb000000000000000000000000000000000000000000000000000000000000"
+            "0000000000000000000000000000000000000000000000000000000000000040"
+            "0000000000000000000000000000000000000000000000000000000000000000"
+            "0000000000000000000000000000000000000000000000000000000000000002"
+            "1234000000000000000000000000000000000000000000000000000000000000"
+        );
+
+        let values = decode_params(&[kind, parse("bytes2[2]")], &data).unwrap();
+
+        assert_eq!(
+            values,
+            vec![
+                Value::Tuple(vec![
+                    Value::Bytes(Data::from(hex!("1234"))),
+                    Value::Bool(false),
+                ]),
+                Value::Array(vec![
+                    Value::FixedBytes(Data::from(hex!("aaaa"))),
+                    Value::FixedBytes(Data::from(hex!("bbbb"))),
+                ]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invalid() {
+        let short = hex!("00");
+        assert!(decode_params(&[parse("uint256")], &short).is_err());
+
+        let bad_bool = hex!("0000000000000000000000000000000000000000000000000000000000000002");
+        assert!(decode_params(&[parse("bool")], &bad_bool).is_err());
+
+        let huge_array = hex!("0000000000000000000000000000000000000000000000000000000000000020" "00000000000000000000000000000000000000000000000000000000ffffffff");
+        assert!(decode_params(&[parse("uint256[]")], &huge_array).is_err());
+    }
+
+    #[test]
+    fn test_huge_fixed_array() {
+        let data = [0; 68];
+
+        for kind in [
+            "uint256[100000000]",
+            "uint256[576460752303423488]",
+            "uint256[576460752303423488][576460752303423488]",
+            "string[576460752303423488]",
+        ] {
+            assert!(decode_params(&[parse(kind)], &data).is_err(), "{kind}");
+        }
+
+        assert!(decode_params(
+            &[parse("uint256"), parse("uint8[576460752303423488]")],
+            &data
+        )
+        .is_err());
+    }
+
+    fn word(val: usize) -> [u8; 32] {
+        let mut word = [0; 32];
+        word[24..].copy_from_slice(&(val as u64).to_be_bytes());
+        word
+    }
+
+    #[test]
+    fn test_shared_bytes() {
+        // bytes[] where every element points at the same 64 KiB value.
+        let (len, size) = (2000, 65536);
+        let mut data = vec![word(32), word(len)];
+        data.extend(std::iter::repeat_n(word(len * 32), len));
+        data.push(word(size));
+        let mut data = data.concat();
+        data.resize(data.len() + size, 0xaa);
+
+        assert!(decode_params(&[parse("bytes[]")], &data).is_err());
+
+        // A single element doesn't share anything.
+        let mut data = [word(32), word(1), word(32), word(size)].concat();
+        data.resize(data.len() + size, 0xaa);
+        assert!(decode_params(&[parse("bytes[]")], &data).is_ok());
+    }
+
+    #[test]
+    fn test_shared_nested_arrays() {
+        // uint256[][][][] where every array element points at the same inner array.
+        let len = 60;
+        let mut data = vec![word(32)];
+        for _ in 0..3 {
+            data.push(word(len));
+            data.extend(std::iter::repeat_n(word(len * 32), len));
+        }
+        data.push(word(len));
+        data.extend(std::iter::repeat_n(word(1), len));
+        let data = data.concat();
+
+        assert!(decode_params(&[parse("uint256[][][][]")], &data).is_err());
+    }
+
+    #[test]
+    fn test_topic() {
+        let topic = hex!("00000000000000000000000000000000000000000000000000000000000000aa");
+
+        assert_eq!(
+            decode_topic(&parse("uint8"), &topic).unwrap(),
+            Value::Uint(Quantity::from([0xaa]))
+        );
+        assert_eq!(
+            decode_topic(&parse("string"), &topic).unwrap(),
+            Value::FixedBytes(Data::from(topic))
+        );
+    }
+}
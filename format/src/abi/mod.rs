@@ -0,0 +1,451 @@
+use crate::types::{Hash, Log, Transaction};
+use crate::{Error, Result};
+use serde::{Deserialize, Serialize};
+use tiny_keccak::{Hasher, Keccak};
+
+mod decode;
+mod param_type;
+mod value;
+
+pub use param_type::ParamType;
+pub use value::Value;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Abi {
+    pub functions: Vec<Function>,
+    pub events: Vec<Event>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Function {
+    name: String,
+    inputs: Vec<Param>,
+    selector: [u8; 4],
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Event {
+    name: String,
+    inputs: Vec<EventParam>,
+    anonymous: bool,
+    selector: Hash,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Param {
+    pub name: String,
+    pub kind: ParamType,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventParam {
+    pub name: String,
+    pub kind: ParamType,
+    pub indexed: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DecodedParam {
+    pub name: String,
+    pub value: Value,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DecodedEvent {
+    pub name: String,
+    pub params: Vec<DecodedParam>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DecodedCall {
+    pub name: String,
+    pub params: Vec<DecodedParam>,
+}
+
+#[derive(Deserialize)]
+struct AbiItemJson {
+    #[serde(rename = "type", default = "default_item_type")]
+    kind: String,
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    inputs: Vec<ParamJson>,
+    #[serde(default)]
+    anonymous: bool,
+}
+
+#[derive(Deserialize)]
+struct ParamJson {
+    #[serde(default)]
+    name: String,
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    indexed: bool,
+    #[serde(default)]
+    components: Vec<ParamJson>,
+}
+
+// Items without a type are functions according to the ABI specification.
+fn default_item_type() -> String {
+    "function".to_owned()
+}
+
+impl ParamJson {
+    fn param_type(&self) -> Result<ParamType> {
+        let components = self
+            .components
+            .iter()
+            .map(ParamJson::param_type)
+            .collect::<Result<Vec<_>>>()?;
+
+        ParamType::parse(&self.kind, components)
+    }
+}
+
+impl Abi {
+    /// Loads the functions and events of a JSON ABI. Other items like errors and constructors
+    /// are ignored.
+    pub fn from_json(json: &str) -> Result<Self> {
+        let items: Vec<AbiItemJson> = serde_json::from_str(json).map_err(Error::ParseAbiJson)?;
+
+        let mut abi = Abi::default();
+
+        for item in items {
+            match item.kind.as_str() {
+                "function" => abi.functions.push(Function::new(
+                    item.name,
+                    item.inputs
+                        .iter()
+                        .map(|p| {
+                            Ok(Param {
+                                name: p.name.clone(),
+                                kind: p.param_type()?,
+                            })
+                        })
+                        .collect::<Result<_>>()?,
+                )),
+                "event" => abi.events.push(Event::new(
+                    item.name,
+                    item.inputs
+                        .iter()
+                        .map(|p| {
+                            Ok(EventParam {
+                                name: p.name.clone(),
+                                kind: p.param_type()?,
+                                indexed: p.indexed,
+                            })
+                        })
+                        .collect::<Result<_>>()?,
+                    item.anonymous,
+                )),
+                _ => (),
+            }
+        }
+
+        Ok(abi)
+    }
+
+    /// Decodes the log with the first event that matches its selector and layout.
+    ///
+    /// Returns `None` if no event in the ABI has the selector in the first topic. Anonymous
+    /// events are never matched since they have no selector.
+    pub fn decode_log(&self, log: &Log) -> Result<Option<DecodedEvent>> {
+        let topic0 = match log.topics.first() {
+            Some(topic0) => topic0,
+            None => return Ok(None),
+        };
+
+        let mut res = Ok(None);
+
+        for event in self.events.iter().filter(|e| !e.anonymous) {
+            if event.selector != *topic0 {
+                continue;
+            }
+
+            match event.decode_log(log) {
+                Ok(decoded) => return Ok(Some(decoded)),
+                Err(e) => res = Err(e),
+            }
+        }
+
+        res
+    }
+
+    /// Decodes the input of the transaction with the function that matches its selector.
+    ///
+    /// Returns `None` if no function in the ABI has the selector.
+    pub fn decode_input(&self, tx: &Transaction) -> Result<Option<DecodedCall>> {
+        let selector = match tx.input.get(..4) {
+            Some(selector) => selector,
+            None => return Ok(None),
+        };
+
+        match self.functions.iter().find(|f| f.selector == selector) {
+            Some(function) => function.decode_input(&tx.input).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+impl Function {
+    pub fn new(name: String, inputs: Vec<Param>) -> Self {
+        let selector = keccak256(signature(&name, inputs.iter().map(|p| &p.kind)).as_bytes())[..4]
+            .try_into()
+            .unwrap();
+
+        Self {
+            name,
+            inputs,
+            selector,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn inputs(&self) -> &[Param] {
+        &self.inputs
+    }
+
+    pub fn signature(&self) -> String {
+        signature(&self.name, self.inputs.iter().map(|p| &p.kind))
+    }
+
+    pub fn selector(&self) -> [u8; 4] {
+        self.selector
+    }
+
+    /// Decodes calldata including the 4 byte selector.
+    pub fn decode_input(&self, input: &[u8]) -> Result<DecodedCall> {
+        if input.get(..4) != Some(self.selector.as_slice()) {
+            return Err(Error::InvalidAbiData(format!(
+                "selector mismatch for function {}",
+                self.name
+            )));
+        }
+
+        let types = self
+            .inputs
+            .iter()
+            .map(|p| p.kind.clone())
+            .collect::<Vec<_>>();
+        let values = decode::decode_params(&types, &input[4..])?;
+
+        Ok(DecodedCall {
+            name: self.name.clone(),
+            params: self
+                .inputs
+                .iter()
+                .zip(values)
+                .map(|(p, value)| DecodedParam {
+                    name: p.name.clone(),
+                    value,
+                })
+                .collect(),
+        })
+    }
+}
+
+impl Event {
+    pub fn new(name: String, inputs: Vec<EventParam>, anonymous: bool) -> Self {
+        let selector = keccak256(signature(&name, inputs.iter().map(|p| &p.kind)).as_bytes());
+
+        Self {
+            name,
+            inputs,
+            anonymous,
+            selector,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn inputs(&self) -> &[EventParam] {
+        &self.inputs
+    }
+
+    pub fn anonymous(&self) -> bool {
+        self.anonymous
+    }
+
+    pub fn signature(&self) -> String {
+        signature(&self.name, self.inputs.iter().map(|p| &p.kind))
+    }
+
+    /// Hash of the signature, matched against the first topic. Anonymous events still have one
+    /// but it isn't emitted.
+    pub fn selector(&self) -> Hash {
+        self.selector.clone()
+    }
+
+    pub fn decode_log(&self, log: &Log) -> Result<DecodedEvent> {
+        let mut topics = log.topics.iter();
+
+        if !self.anonymous && topics.next() != Some(&self.selector) {
+            return Err(Error::InvalidAbiData(format!(
+                "selector mismatch for event {}",
+                self.name
+            )));
+        }
+
+        let num_indexed = self.inputs.iter().filter(|p| p.indexed).count();
+        if topics.len() != num_indexed {
+            return Err(Error::InvalidAbiData(format!(
+                "expected {} indexed topics for event {} got {}",
+                num_indexed,
+                self.name,
+                topics.len()
+            )));
+        }
+
+        let types = self
+            .inputs
+            .iter()
+            .filter(|p| !p.indexed)
+            .map(|p| p.kind.clone())
+            .collect::<Vec<_>>();
+        let mut values = decode::decode_params(&types, &log.data)?.into_iter();
+
+        let params = self
+            .inputs
+            .iter()
+            .map(|p| {
+                let value = if p.indexed {
+                    decode::decode_topic(&p.kind, topics.next().unwrap())?
+                } else {
+                    values.next().unwrap()
+                };
+
+                Ok(DecodedParam {
+                    name: p.name.clone(),
+                    value,
+                })
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(DecodedEvent {
+            name: self.name.clone(),
+            params,
+        })
+    }
+}
+
+fn signature<'a>(name: &str, types: impl Iterator<Item = &'a ParamType>) -> String {
+    let types = types.map(ToString::to_string).collect::<Vec<_>>();
+    format!("{}({})", name, types.join(","))
+}
+
+pub fn keccak256(data: &[u8]) -> Hash {
+    let mut hasher = Keccak::v256();
+    hasher.update(data);
+
+    let mut out = [0; 32];
+    hasher.finalize(&mut out);
+
+    Hash::from(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Abi, DecodedParam, Value};
+    use crate::test_util::addr_word;
+    use crate::types::{Address, Data, Log, Quantity, Transaction};
+    use hex_literal::hex;
+
+    const ERC20_ABI: &str = r#"[
+        {"type":"function","name":"transfer","stateMutability":"nonpayable","inputs":[{"name":"to","type":"address"},{"name":"amount","type":"uint256"}],"outputs":[{"name":"","type":"bool"}]},
+        {"type":"event","name":"Transfer","anonymous":false,"inputs":[{"name":"from","type":"address","indexed":true},{"name":"to","type":"address","indexed":true},{"name":"value","type":"uint256","indexed":false}]},
+        {"type":"error","name":"InsufficientBalance","inputs":[]},
+        {"type":"constructor","inputs":[{"name":"supply","type":"uint256"}]}
+    ]"#;
+
+    #[test]
+    fn test_from_json() {
+        let abi = Abi::from_json(ERC20_ABI).unwrap();
+
+        assert_eq!(abi.functions.len(), 1);
+        assert_eq!(abi.events.len(), 1);
+        assert_eq!(abi.functions[0].signature(), "transfer(address,uint256)");
+        assert_eq!(abi.functions[0].selector(), hex!("a9059cbb"));
+        assert_eq!(
+            abi.events[0].selector(),
+            hex!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef").into()
+        );
+    }
+
+    #[test]
+    fn test_from_json_invalid_type() {
+        assert!(Abi::from_json(
+            r#"[{"type":"event","name":"A","inputs":[{"name":"a","type":"uint7"}]}]"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_decode_log() {
+        let abi = Abi::from_json(ERC20_ABI).unwrap();
+        let mut log = Log {
+            topics: [abi.events[0].selector(), addr_word(1), addr_word(2)]
+                .into_iter()
+                .collect(),
+            data: hex!("0000000000000000000000000000000000000000000000000000000000000010").into(),
+            ..Default::default()
+        };
+
+        let decoded = abi.decode_log(&log).unwrap().unwrap();
+        assert_eq!(decoded.name, "Transfer");
+        assert_eq!(
+            decoded.params,
+            vec![
+                DecodedParam {
+                    name: "from".to_owned(),
+                    value: Value::Address(Address::from([1; 20])),
+                },
+                DecodedParam {
+                    name: "to".to_owned(),
+                    value: Value::Address(Address::from([2; 20])),
+                },
+                DecodedParam {
+                    name: "value".to_owned(),
+                    value: Value::Uint(Quantity::from([0x10])),
+                },
+            ]
+        );
+
+        // ERC-721 style transfer with the same selector doesn't fit the ERC-20 layout.
+        log.topics.push(addr_word(3));
+        log.data = Data::default();
+        assert!(abi.decode_log(&log).is_err());
+
+        log.topics[0] = addr_word(4);
+        assert_eq!(abi.decode_log(&log).unwrap(), None);
+    }
+
+    #[test]
+    fn test_decode_input() {
+        let abi = Abi::from_json(ERC20_ABI).unwrap();
+        let tx = Transaction {
+            input: Data::from(hex!(
+                "a9059cbb"
+                "0000000000000000000000000202020202020202020202020202020202020202"
+                "0000000000000000000000000000000000000000000000000000000000000010"
+            )),
+            ..Default::default()
+        };
+
+        let decoded = abi.decode_input(&tx).unwrap().unwrap();
+        assert_eq!(decoded.name, "transfer");
+        assert_eq!(
+            decoded.params[0].value,
+            Value::Address(Address::from([2; 20]))
+        );
+        assert_eq!(decoded.params[1].value, Value::Uint(Quantity::from([0x10])));
+
+        let empty = Transaction::default();
+        assert_eq!(abi.decode_input(&empty).unwrap(), None);
+    }
+}
@@ -0,0 +1,203 @@
+use crate::{Error, Result};
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParamType {
+    Address,
+    Bool,
+    Int(usize),
+    Uint(usize),
+    FixedBytes(usize),
+    Function,
+    Bytes,
+    String,
+    Array(Box<ParamType>),
+    FixedArray(Box<ParamType>, usize),
+    Tuple(Vec<ParamType>),
+}
+
+impl ParamType {
+    /// Parses a type string from a JSON ABI, e.g. `uint256`, `bytes32[]` or `tuple[2]`.
+    ///
+    /// `components` are the member types of a tuple and are only used if the base type is `tuple`.
+    pub fn parse(kind: &str, components: Vec<ParamType>) -> Result<Self> {
+        if let Some(prefix) = kind.strip_suffix(']') {
+            let open = prefix
+                .rfind('[')
+                .ok_or_else(|| Error::InvalidAbiParamType(kind.to_owned()))?;
+            let inner = Box::new(Self::parse(&prefix[..open], components)?);
+            let size = &prefix[open + 1..];
+
+            if size.is_empty() {
+                return Ok(Self::Array(inner));
+            }
+
+            return match size.parse::<usize>() {
+                Ok(size) if size > 0 => Ok(Self::FixedArray(inner, size)),
+                _ => Err(Error::InvalidAbiParamType(kind.to_owned())),
+            };
+        }
+
+        let invalid = || Error::InvalidAbiParamType(kind.to_owned());
+
+        match kind {
+            "address" => Ok(Self::Address),
+            "bool" => Ok(Self::Bool),
+            "int" => Ok(Self::Int(256)),
+            "uint" => Ok(Self::Uint(256)),
+            "function" => Ok(Self::Function),
+            "bytes" => Ok(Self::Bytes),
+            "string" => Ok(Self::String),
+            // Empty tuples would have a zero head size, solidity doesn't allow them either.
+            "tuple" if !components.is_empty() => Ok(Self::Tuple(components)),
+            _ => {
+                if let Some(bits) = kind.strip_prefix("uint") {
+                    parse_bits(bits).map(Self::Uint).ok_or_else(invalid)
+                } else if let Some(bits) = kind.strip_prefix("int") {
+                    parse_bits(bits).map(Self::Int).ok_or_else(invalid)
+                } else if let Some(size) = kind.strip_prefix("bytes") {
+                    match size.parse::<usize>() {
+                        Ok(size) if (1..=32).contains(&size) => Ok(Self::FixedBytes(size)),
+                        _ => Err(invalid()),
+                    }
+                } else {
+                    Err(invalid())
+                }
+            }
+        }
+    }
+
+    pub fn is_dynamic(&self) -> bool {
+        match self {
+            Self::Bytes | Self::String | Self::Array(_) => true,
+            Self::FixedArray(inner, _) => inner.is_dynamic(),
+            Self::Tuple(types) => types.iter().any(Self::is_dynamic),
+            _ => false,
+        }
+    }
+
+    /// Size of the head of this type in the ABI encoding, `None` if it doesn't fit into `usize`.
+    pub(crate) fn head_size(&self) -> Option<usize> {
+        if self.is_dynamic() {
+            return Some(32);
+        }
+
+        match self {
+            Self::FixedArray(inner, size) => inner.head_size()?.checked_mul(*size),
+            Self::Tuple(types) => sequence_head_size(types),
+            _ => Some(32),
+        }
+    }
+}
+
+/// Combined head size of a sequence of types, `None` if it doesn't fit into `usize`.
+pub(crate) fn sequence_head_size(types: &[ParamType]) -> Option<usize> {
+    types
+        .iter()
+        .try_fold(0usize, |size, kind| size.checked_add(kind.head_size()?))
+}
+
+fn parse_bits(bits: &str) -> Option<usize> {
+    match bits.parse::<usize>() {
+        Ok(bits) if bits > 0 && bits <= 256 && bits % 8 == 0 => Some(bits),
+        _ => None,
+    }
+}
+
+impl fmt::Display for ParamType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Address => f.write_str("address"),
+            Self::Bool => f.write_str("bool"),
+            Self::Int(bits) => write!(f, "int{bits}"),
+            Self::Uint(bits) => write!(f, "uint{bits}"),
+            Self::FixedBytes(size) => write!(f, "bytes{size}"),
+            Self::Function => f.write_str("function"),
+            Self::Bytes => f.write_str("bytes"),
+            Self::String => f.write_str("string"),
+            Self::Array(inner) => write!(f, "{inner}[]"),
+            Self::FixedArray(inner, size) => write!(f, "{inner}[{size}]"),
+            Self::Tuple(types) => {
+                f.write_str("(")?;
+                for (i, kind) in types.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(",")?;
+                    }
+                    write!(f, "{kind}")?;
+                }
+                f.write_str(")")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ParamType;
+
+    fn parse(kind: &str) -> ParamType {
+        ParamType::parse(kind, Vec::new()).unwrap()
+    }
+
+    #[test]
+    fn test_parse_elementary() {
+        assert_eq!(parse("address"), ParamType::Address);
+        assert_eq!(parse("uint"), ParamType::Uint(256));
+        assert_eq!(parse("int8"), ParamType::Int(8));
+        assert_eq!(parse("bytes4"), ParamType::FixedBytes(4));
+        assert_eq!(parse("bytes"), ParamType::Bytes);
+    }
+
+    #[test]
+    fn test_parse_arrays() {
+        assert_eq!(
+            parse("uint256[2][]"),
+            ParamType::Array(Box::new(ParamType::FixedArray(
+                Box::new(ParamType::Uint(256)),
+                2
+            )))
+        );
+        assert_eq!(
+            ParamType::parse("tuple[]", vec![ParamType::Bool, ParamType::String]).unwrap(),
+            ParamType::Array(Box::new(ParamType::Tuple(vec![
+                ParamType::Bool,
+                ParamType::String
+            ])))
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        for kind in [
+            "uint7",
+            "uint264",
+            "bytes0",
+            "bytes33",
+            "uint256[0]",
+            "uint256]",
+            "foo",
+        ] {
+            assert!(ParamType::parse(kind, Vec::new()).is_err(), "{kind}");
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        let kind = ParamType::parse("tuple[2]", vec![parse("address"), parse("bytes[]")]).unwrap();
+        assert_eq!(kind.to_string(), "(address,bytes[])[2]");
+    }
+
+    #[test]
+    fn test_dynamic() {
+        assert!(!parse("uint256[2]").is_dynamic());
+        assert!(parse("string[2]").is_dynamic());
+        assert_eq!(parse("uint256[2][3]").head_size(), Some(6 * 32));
+        assert_eq!(parse("string[2]").head_size(), Some(32));
+        assert_eq!(
+            parse("uint256[576460752303423488][576460752303423488]").head_size(),
+            None
+        );
+        let huge = parse("uint256[576460752303423488]");
+        assert_eq!(ParamType::Tuple(vec![huge.clone(), huge]).head_size(), None);
+    }
+}
@@ -0,0 +1,17 @@
+use crate::types::{Address, Data, FixedSizeData, Quantity};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value", rename_all = "camelCase")]
+pub enum Value {
+    Address(Address),
+    Bool(bool),
+    /// Sign extended two's complement representation.
+    Int(FixedSizeData<32>),
+    Uint(Quantity),
+    FixedBytes(Data),
+    Bytes(Data),
+    String(String),
+    Array(Vec<Value>),
+    Tuple(Vec<Value>),
+}
@@ -1,3 +1,4 @@
+use crate::types::Quantity;
 use std::result::Result as StdResult;
 use thiserror::Error as ThisError;
 
@@ -15,6 +16,28 @@ pub enum Error {
     UnknownTransactionType(String),
     #[error("Unexpected quantity. Value was: {0}")]
     UnexpectedQuantity(String),
+    #[error("Unknown call type: {0}")]
+    UnknownCallType(String),
+    #[error("Unknown trace type: {0}")]
+    UnknownTraceType(String),
+    #[error("Unknown trace call type: {0}")]
+    UnknownTraceCallType(String),
+    #[error("Unknown token standard: {0}")]
+    UnknownTokenStandard(String),
+    #[error("Failed to parse ABI JSON:\n{0}")]
+    ParseAbiJson(serde_json::Error),
+    #[error("Invalid ABI parameter type: {0}")]
+    InvalidAbiParamType(String),
+    #[error("Invalid ABI encoded data: {0}")]
+    InvalidAbiData(String),
+    #[error("Quantity doesn't fit into 128 bits: {0:?}")]
+    QuantityOverflow(Quantity),
+    #[error("Reward percentiles must be increasing values between 0 and 100. Value was: {0:?}")]
+    InvalidRewardPercentiles(Vec<f64>),
+    #[error("Unknown block tag: {0}")]
+    UnknownBlockTag(String),
+    #[error("Invalid block number: {0}")]
+    InvalidBlockNumber(String),
 }
 
 pub type Result<T> = StdResult<T, Error>;
@@ -1,4 +1,8 @@
+pub mod abi;
 mod error;
 pub mod types;
 
+#[cfg(test)]
+mod test_util;
+
 pub use error::{Error, Result};
@@ -0,0 +1,55 @@
+use crate::types::{
+    Address, CallFrame, CallType, Hash, LogArgument, Quantity, TransactionReceipt,
+    TransactionStatus, TransactionType,
+};
+
+pub(crate) fn call_frame(
+    kind: CallType,
+    from: u8,
+    to: u8,
+    value: u8,
+    calls: Vec<CallFrame>,
+) -> CallFrame {
+    CallFrame {
+        kind,
+        from: Address::from([from; 20]),
+        to: Some(Address::from([to; 20])),
+        value: Some(Quantity::from([value])),
+        gas: Quantity::default(),
+        gas_used: Quantity::default(),
+        input: Default::default(),
+        output: None,
+        error: None,
+        revert_reason: None,
+        calls: calls.into(),
+    }
+}
+
+/// A successful legacy transaction receipt without logs, meant to be overridden with struct update
+/// syntax.
+pub(crate) fn receipt() -> TransactionReceipt {
+    TransactionReceipt {
+        transaction_hash: Hash::from([7; 32]),
+        transaction_index: 0.into(),
+        block_hash: Hash::default(),
+        block_number: 11.into(),
+        from: Address::from([1; 20]),
+        to: None,
+        cumulative_gas_used: Quantity::default(),
+        effective_gas_price: Quantity::default(),
+        gas_used: Quantity::default(),
+        contract_address: None,
+        logs: Box::default(),
+        logs_bloom: Default::default(),
+        kind: TransactionType::Legacy,
+        root: None,
+        status: Some(TransactionStatus::Success),
+    }
+}
+
+/// A 32 byte word holding an address filled with `byte`.
+pub(crate) fn addr_word(byte: u8) -> LogArgument {
+    let mut buf = [0; 32];
+    buf[12..].copy_from_slice(&[byte; 20]);
+    LogArgument::from(buf)
+}
@@ -0,0 +1,257 @@
+use super::{BlockNumberOrTag, Hash};
+use serde::de::{self, MapAccess, Visitor};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::result::Result as StdResult;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BlockId {
+    Number(BlockNumberOrTag),
+    Hash { hash: Hash, require_canonical: bool },
+}
+
+impl Default for BlockId {
+    fn default() -> Self {
+        Self::Number(BlockNumberOrTag::default())
+    }
+}
+
+impl From<BlockNumberOrTag> for BlockId {
+    fn from(num: BlockNumberOrTag) -> Self {
+        Self::Number(num)
+    }
+}
+
+impl From<Hash> for BlockId {
+    fn from(hash: Hash) -> Self {
+        Self::Hash {
+            hash,
+            require_canonical: false,
+        }
+    }
+}
+
+const BLOCK_NUMBER: &str = "blockNumber";
+const BLOCK_HASH: &str = "blockHash";
+const REQUIRE_CANONICAL: &str = "requireCanonical";
+
+struct BlockIdVisitor;
+
+impl<'de> Visitor<'de> for BlockIdVisitor {
+    type Value = BlockId;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("block number, block tag or EIP-1898 block identifier object")
+    }
+
+    fn visit_str<E>(self, value: &str) -> StdResult<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        BlockNumberOrTag::from_str(value)
+            .map(BlockId::Number)
+            .map_err(|e| E::custom(e.to_string()))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> StdResult<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut number: Option<BlockNumberOrTag> = None;
+        let mut hash: Option<Hash> = None;
+        let mut require_canonical: Option<bool> = None;
+
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                BLOCK_NUMBER => {
+                    if number.is_some() {
+                        return Err(de::Error::duplicate_field(BLOCK_NUMBER));
+                    }
+                    number = Some(map.next_value()?);
+                }
+                BLOCK_HASH => {
+                    if hash.is_some() {
+                        return Err(de::Error::duplicate_field(BLOCK_HASH));
+                    }
+                    hash = Some(map.next_value()?);
+                }
+                REQUIRE_CANONICAL => {
+                    if require_canonical.is_some() {
+                        return Err(de::Error::duplicate_field(REQUIRE_CANONICAL));
+                    }
+                    require_canonical = Some(map.next_value()?);
+                }
+                key => {
+                    return Err(de::Error::unknown_field(
+                        key,
+                        &[BLOCK_NUMBER, BLOCK_HASH, REQUIRE_CANONICAL],
+                    ))
+                }
+            }
+        }
+
+        match (number, hash) {
+            (Some(number), None) => {
+                if require_canonical.is_some() {
+                    return Err(de::Error::custom(
+                        "requireCanonical can only be used together with blockHash",
+                    ));
+                }
+
+                Ok(BlockId::Number(number))
+            }
+            (None, Some(hash)) => Ok(BlockId::Hash {
+                hash,
+                require_canonical: require_canonical.unwrap_or_default(),
+            }),
+            (Some(_), Some(_)) => Err(de::Error::custom(
+                "blockNumber and blockHash can't be used together",
+            )),
+            (None, None) => Err(de::Error::custom("expected blockNumber or blockHash")),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for BlockId {
+    fn deserialize<D>(deserializer: D) -> StdResult<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(BlockIdVisitor)
+    }
+}
+
+impl Serialize for BlockId {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::Number(num) => num.serialize(serializer),
+            Self::Hash {
+                hash,
+                require_canonical,
+            } => {
+                let mut s = serializer.serialize_struct("BlockId", 2)?;
+                s.serialize_field(BLOCK_HASH, hash)?;
+                s.serialize_field(REQUIRE_CANONICAL, require_canonical)?;
+                s.end()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BlockId;
+    use crate::types::{BlockNumber, BlockNumberOrTag, BlockTag, Hash};
+    use hex_literal::hex;
+    use serde_test::{assert_de_tokens, assert_de_tokens_error, assert_tokens, Token};
+
+    const HASH: &str = "0x00000000000000000000000000000000000000000000000000000000000000aa";
+
+    fn hash() -> Hash {
+        Hash::from(hex!(
+            "00000000000000000000000000000000000000000000000000000000000000aa"
+        ))
+    }
+
+    #[test]
+    fn test_serde_number_or_tag() {
+        assert_tokens(
+            &BlockId::Number(BlockNumberOrTag::Number(BlockNumber::from(19))),
+            &[Token::Str("0x13")],
+        );
+        assert_tokens(
+            &BlockId::Number(BlockNumberOrTag::Tag(BlockTag::Safe)),
+            &[Token::Str("safe")],
+        );
+    }
+
+    #[test]
+    fn test_serde_hash() {
+        assert_tokens(
+            &BlockId::Hash {
+                hash: hash(),
+                require_canonical: true,
+            },
+            &[
+                Token::Struct {
+                    name: "BlockId",
+                    len: 2,
+                },
+                Token::Str("blockHash"),
+                Token::Str(HASH),
+                Token::Str("requireCanonical"),
+                Token::Bool(true),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_de_hash_default_require_canonical() {
+        assert_de_tokens(
+            &BlockId::from(hash()),
+            &[
+                Token::Map { len: Some(1) },
+                Token::Str("blockHash"),
+                Token::Str(HASH),
+                Token::MapEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_de_block_number_object() {
+        assert_de_tokens(
+            &BlockId::Number(BlockNumberOrTag::Number(BlockNumber::from(19))),
+            &[
+                Token::Map { len: Some(1) },
+                Token::Str("blockNumber"),
+                Token::Str("0x13"),
+                Token::MapEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_de_number_and_hash() {
+        assert_de_tokens_error::<BlockId>(
+            &[
+                Token::Map { len: Some(2) },
+                Token::Str("blockNumber"),
+                Token::Str("0x13"),
+                Token::Str("blockHash"),
+                Token::Str(HASH),
+                Token::MapEnd,
+            ],
+            "blockNumber and blockHash can't be used together",
+        );
+    }
+
+    #[test]
+    fn test_de_require_canonical_without_hash() {
+        assert_de_tokens_error::<BlockId>(
+            &[
+                Token::Map { len: Some(2) },
+                Token::Str("blockNumber"),
+                Token::Str("0x13"),
+                Token::Str("requireCanonical"),
+                Token::Bool(false),
+                Token::MapEnd,
+            ],
+            "requireCanonical can only be used together with blockHash",
+        );
+    }
+
+    #[test]
+    fn test_de_empty_object() {
+        assert_de_tokens_error::<BlockId>(
+            &[Token::Map { len: Some(0) }, Token::MapEnd],
+            "expected blockNumber or blockHash",
+        );
+    }
+}
@@ -0,0 +1,113 @@
+use super::{BlockNumber, BlockTag};
+use crate::{Error, Result};
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::result::Result as StdResult;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, derive_more::From)]
+pub enum BlockNumberOrTag {
+    Number(BlockNumber),
+    Tag(BlockTag),
+}
+
+impl Default for BlockNumberOrTag {
+    fn default() -> Self {
+        Self::Tag(BlockTag::Latest)
+    }
+}
+
+impl FromStr for BlockNumberOrTag {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.strip_prefix("0x") {
+            Some(val) => u64::from_str_radix(val, 16)
+                .map(|num| Self::Number(num.into()))
+                .map_err(|_| Error::InvalidBlockNumber(s.to_owned())),
+            None => BlockTag::from_str(s).map(Self::Tag),
+        }
+    }
+}
+
+struct BlockNumberOrTagVisitor;
+
+impl<'de> Visitor<'de> for BlockNumberOrTagVisitor {
+    type Value = BlockNumberOrTag;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("hex string for block number or a block tag")
+    }
+
+    fn visit_str<E>(self, value: &str) -> StdResult<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        BlockNumberOrTag::from_str(value).map_err(|e| E::custom(e.to_string()))
+    }
+}
+
+impl<'de> Deserialize<'de> for BlockNumberOrTag {
+    fn deserialize<D>(deserializer: D) -> StdResult<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(BlockNumberOrTagVisitor)
+    }
+}
+
+impl Serialize for BlockNumberOrTag {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::Number(num) => num.serialize(serializer),
+            Self::Tag(tag) => tag.serialize(serializer),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BlockNumberOrTag;
+    use crate::types::{BlockNumber, BlockTag};
+    use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+
+    #[test]
+    fn test_serde_number() {
+        assert_tokens(
+            &BlockNumberOrTag::Number(BlockNumber::from(0)),
+            &[Token::Str("0x0")],
+        );
+        assert_tokens(
+            &BlockNumberOrTag::Number(BlockNumber::from(19)),
+            &[Token::Str("0x13")],
+        );
+    }
+
+    #[test]
+    fn test_serde_tag() {
+        assert_tokens(
+            &BlockNumberOrTag::Tag(BlockTag::Finalized),
+            &[Token::Str("finalized")],
+        );
+    }
+
+    #[test]
+    fn test_de_invalid_number() {
+        assert_de_tokens_error::<BlockNumberOrTag>(
+            &[Token::Str("0xzz")],
+            "Invalid block number: 0xzz",
+        );
+    }
+
+    #[test]
+    fn test_de_unknown_tag() {
+        assert_de_tokens_error::<BlockNumberOrTag>(
+            &[Token::Str("newest")],
+            "Unknown block tag: newest",
+        );
+    }
+}
@@ -0,0 +1,97 @@
+use crate::{Error, Result};
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::result::Result as StdResult;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BlockTag {
+    Latest,
+    Safe,
+    Finalized,
+    Earliest,
+    Pending,
+}
+
+impl FromStr for BlockTag {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "latest" => Ok(Self::Latest),
+            "safe" => Ok(Self::Safe),
+            "finalized" => Ok(Self::Finalized),
+            "earliest" => Ok(Self::Earliest),
+            "pending" => Ok(Self::Pending),
+            _ => Err(Error::UnknownBlockTag(s.to_owned())),
+        }
+    }
+}
+
+impl BlockTag {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Latest => "latest",
+            Self::Safe => "safe",
+            Self::Finalized => "finalized",
+            Self::Earliest => "earliest",
+            Self::Pending => "pending",
+        }
+    }
+}
+
+struct BlockTagVisitor;
+
+impl<'de> Visitor<'de> for BlockTagVisitor {
+    type Value = BlockTag;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("block tag string")
+    }
+
+    fn visit_str<E>(self, value: &str) -> StdResult<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        BlockTag::from_str(value).map_err(|e| E::custom(e.to_string()))
+    }
+}
+
+impl<'de> Deserialize<'de> for BlockTag {
+    fn deserialize<D>(deserializer: D) -> StdResult<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(BlockTagVisitor)
+    }
+}
+
+impl Serialize for BlockTag {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BlockTag;
+    use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+
+    #[test]
+    fn test_serde() {
+        assert_tokens(&BlockTag::Latest, &[Token::Str("latest")]);
+        assert_tokens(&BlockTag::Safe, &[Token::Str("safe")]);
+        assert_tokens(&BlockTag::Finalized, &[Token::Str("finalized")]);
+        assert_tokens(&BlockTag::Earliest, &[Token::Str("earliest")]);
+        assert_tokens(&BlockTag::Pending, &[Token::Str("pending")]);
+    }
+
+    #[test]
+    fn test_de_unknown() {
+        assert_de_tokens_error::<BlockTag>(&[Token::Str("Latest")], "Unknown block tag: Latest");
+    }
+}
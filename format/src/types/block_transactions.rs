@@ -0,0 +1,245 @@
+use super::{Hash, Transaction};
+use serde::de::{self, value::MapAccessDeserializer, MapAccess, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::result::Result as StdResult;
+
+#[derive(Debug, Clone, PartialEq, Eq, derive_more::From)]
+pub enum BlockTransactions {
+    Hashes(Box<[Hash]>),
+    Full(Box<[Transaction]>),
+}
+
+impl Default for BlockTransactions {
+    fn default() -> Self {
+        Self::Full(Box::default())
+    }
+}
+
+impl BlockTransactions {
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Hashes(hashes) => hashes.len(),
+            Self::Full(txs) => txs.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn into_hashes(self) -> Self {
+        match self {
+            Self::Hashes(hashes) => Self::Hashes(hashes),
+            Self::Full(txs) => Self::Hashes(txs.into_vec().into_iter().map(|tx| tx.hash).collect()),
+        }
+    }
+}
+
+enum HashOrTransaction {
+    Hash(Hash),
+    Transaction(Transaction),
+}
+
+struct HashOrTransactionVisitor;
+
+impl<'de> Visitor<'de> for HashOrTransactionVisitor {
+    type Value = HashOrTransaction;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("transaction hash or transaction object")
+    }
+
+    fn visit_str<E>(self, value: &str) -> StdResult<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Hash::deserialize(de::value::StrDeserializer::new(value)).map(HashOrTransaction::Hash)
+    }
+
+    fn visit_map<A>(self, map: A) -> StdResult<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        Transaction::deserialize(MapAccessDeserializer::new(map))
+            .map(HashOrTransaction::Transaction)
+    }
+}
+
+impl<'de> Deserialize<'de> for HashOrTransaction {
+    fn deserialize<D>(deserializer: D) -> StdResult<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(HashOrTransactionVisitor)
+    }
+}
+
+struct BlockTransactionsVisitor;
+
+impl<'de> Visitor<'de> for BlockTransactionsVisitor {
+    type Value = BlockTransactions;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("array of transaction hashes or transaction objects")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> StdResult<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        // The first element decides the kind of the whole list. An empty list is
+        // treated as a full transaction list.
+        match seq.next_element::<HashOrTransaction>()? {
+            None => Ok(BlockTransactions::default()),
+            Some(HashOrTransaction::Hash(hash)) => {
+                let mut hashes = vec![hash];
+                while let Some(hash) = seq.next_element()? {
+                    hashes.push(hash);
+                }
+                Ok(BlockTransactions::Hashes(hashes.into()))
+            }
+            Some(HashOrTransaction::Transaction(tx)) => {
+                let mut txs = vec![tx];
+                while let Some(tx) = seq.next_element()? {
+                    txs.push(tx);
+                }
+                Ok(BlockTransactions::Full(txs.into()))
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for BlockTransactions {
+    fn deserialize<D>(deserializer: D) -> StdResult<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(BlockTransactionsVisitor)
+    }
+}
+
+impl Serialize for BlockTransactions {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::Hashes(hashes) => hashes.serialize(serializer),
+            Self::Full(txs) => txs.serialize(serializer),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BlockTransactions;
+    use crate::types::{Hash, Transaction};
+    use hex_literal::hex;
+    use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+
+    const HASH: &str = "0x00000000000000000000000000000000000000000000000000000000000000aa";
+    const ZERO_HASH: &str = "0x0000000000000000000000000000000000000000000000000000000000000000";
+    const ZERO_ADDRESS: &str = "0x0000000000000000000000000000000000000000";
+
+    fn hash() -> Hash {
+        Hash::from(hex!(
+            "00000000000000000000000000000000000000000000000000000000000000aa"
+        ))
+    }
+
+    #[test]
+    fn test_serde_empty() {
+        assert_tokens(
+            &BlockTransactions::default(),
+            &[Token::Seq { len: Some(0) }, Token::SeqEnd],
+        );
+    }
+
+    #[test]
+    fn test_serde_hashes() {
+        assert_tokens(
+            &BlockTransactions::Hashes(vec![hash(), hash()].into()),
+            &[
+                Token::Seq { len: Some(2) },
+                Token::Str(HASH),
+                Token::Str(HASH),
+                Token::SeqEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_serde_full() {
+        let tx = Transaction {
+            hash: hash(),
+            ..Default::default()
+        };
+
+        assert_tokens(
+            &BlockTransactions::Full(vec![tx].into()),
+            &[
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "Transaction",
+                    len: 14,
+                },
+                Token::Str("blockHash"),
+                Token::Str(ZERO_HASH),
+                Token::Str("blockNumber"),
+                Token::Str("0x0"),
+                Token::Str("from"),
+                Token::Str(ZERO_ADDRESS),
+                Token::Str("gas"),
+                Token::Str("0x0"),
+                Token::Str("gasPrice"),
+                Token::Str("0x0"),
+                Token::Str("hash"),
+                Token::Str(HASH),
+                Token::Str("input"),
+                Token::Str("0x"),
+                Token::Str("nonce"),
+                Token::Str("0x0"),
+                Token::Str("to"),
+                Token::None,
+                Token::Str("transactionIndex"),
+                Token::Str("0x0"),
+                Token::Str("value"),
+                Token::Str("0x0"),
+                Token::Str("v"),
+                Token::Str("0x0"),
+                Token::Str("r"),
+                Token::Str("0x0"),
+                Token::Str("s"),
+                Token::Str("0x0"),
+                Token::StructEnd,
+                Token::SeqEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_into_hashes() {
+        let tx = Transaction {
+            hash: hash(),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            BlockTransactions::Full(vec![tx].into()).into_hashes(),
+            BlockTransactions::Hashes(vec![hash()].into())
+        );
+    }
+
+    #[test]
+    fn test_de_mixed() {
+        assert_de_tokens_error::<BlockTransactions>(
+            &[
+                Token::Seq { len: Some(2) },
+                Token::Str(HASH),
+                Token::Map { len: Some(0) },
+            ],
+            "invalid type: map, expected hex string for 32 byte data",
+        );
+    }
+}
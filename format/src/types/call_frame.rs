@@ -0,0 +1,136 @@
+use super::{Address, CallType, Data, Hash, Quantity};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CallFrame {
+    #[serde(rename = "type")]
+    pub kind: CallType,
+    pub from: Address,
+    pub to: Option<Address>,
+    pub value: Option<Quantity>,
+    pub gas: Quantity,
+    pub gas_used: Quantity,
+    pub input: Data,
+    pub output: Option<Data>,
+    pub error: Option<String>,
+    pub revert_reason: Option<String>,
+    #[serde(default)]
+    pub calls: Box<[CallFrame]>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionCallTrace {
+    pub tx_hash: Hash,
+    pub result: CallFrame,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TransactionCallTrace;
+    use crate::types::{Address, CallType, Data, Quantity};
+    use hex_literal::hex;
+
+    // debug_traceBlockByNumber with {"tracer": "callTracer"}
+    const BLOCK_TRACE: &str = r#"[
+        {
+            "txHash": "0x9b6e7e1a4b0e6ec2d2b6c1f7ad0b3b8a1e0c5d2f7a9b8c6d4e3f2a1b0c9d8e7f",
+            "result": {
+                "from": "0x25f2e0ddd6c0a4a4b6dbd3ef0d8a1c7e9c0a4b0e",
+                "gas": "0x1bb9a",
+                "gasUsed": "0x7a1f",
+                "to": "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+                "input": "0xa9059cbb",
+                "output": "0x0000000000000000000000000000000000000000000000000000000000000001",
+                "calls": [
+                    {
+                        "from": "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+                        "gas": "0x1a2c4",
+                        "gasUsed": "0x5dd1",
+                        "to": "0x43506849d7c04f9138d1a2050bbf3a0c054402dd",
+                        "input": "0xa9059cbb",
+                        "output": "0x",
+                        "type": "DELEGATECALL"
+                    },
+                    {
+                        "from": "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+                        "gas": "0x2710",
+                        "gasUsed": "0x2710",
+                        "to": "0x0000000000000000000000000000000000000001",
+                        "input": "0x",
+                        "error": "execution reverted",
+                        "revertReason": "not allowed",
+                        "value": "0x16345785d8a0000",
+                        "type": "CALL"
+                    }
+                ],
+                "value": "0x0",
+                "type": "CALL"
+            }
+        },
+        {
+            "txHash": "0x1c6e7e1a4b0e6ec2d2b6c1f7ad0b3b8a1e0c5d2f7a9b8c6d4e3f2a1b0c9d8e7f",
+            "result": {
+                "from": "0x25f2e0ddd6c0a4a4b6dbd3ef0d8a1c7e9c0a4b0e",
+                "gas": "0x30d40",
+                "gasUsed": "0x2a2f1",
+                "to": "0x5fbdb2315678afecb367f032d93f642f64180aa3",
+                "input": "0x6080604052",
+                "output": "0x6080",
+                "value": "0x0",
+                "type": "CREATE"
+            }
+        }
+    ]"#;
+
+    #[test]
+    fn test_de_call_tracer() {
+        let traces: Vec<TransactionCallTrace> = serde_json::from_str(BLOCK_TRACE).unwrap();
+        assert_eq!(traces.len(), 2);
+
+        let root = &traces[0].result;
+        assert_eq!(root.kind, CallType::Call);
+        assert_eq!(
+            root.from,
+            Address::from(hex!("25f2e0ddd6c0a4a4b6dbd3ef0d8a1c7e9c0a4b0e"))
+        );
+        assert_eq!(root.value, Some(Quantity::default()));
+        assert_eq!(root.gas_used, Quantity::from(hex!("7a1f")));
+        assert_eq!(root.input, Data::from(hex!("a9059cbb")));
+        assert_eq!(root.error, None);
+        assert_eq!(root.calls.len(), 2);
+
+        let delegate = &root.calls[0];
+        assert_eq!(delegate.kind, CallType::DelegateCall);
+        assert_eq!(delegate.value, None);
+        assert_eq!(delegate.output, Some(Data::default()));
+        assert!(delegate.calls.is_empty());
+
+        let failed = &root.calls[1];
+        assert_eq!(failed.error.as_deref(), Some("execution reverted"));
+        assert_eq!(failed.revert_reason.as_deref(), Some("not allowed"));
+        assert_eq!(failed.output, None);
+        assert_eq!(failed.value, Some(Quantity::from(hex!("016345785d8a0000"))));
+
+        let create = &traces[1].result;
+        assert_eq!(create.kind, CallType::Create);
+        assert_eq!(
+            create.to,
+            Some(Address::from(hex!(
+                "5fbdb2315678afecb367f032d93f642f64180aa3"
+            )))
+        );
+    }
+
+    #[test]
+    fn test_serde_roundtrip() {
+        let traces: Vec<TransactionCallTrace> = serde_json::from_str(BLOCK_TRACE).unwrap();
+        let json = serde_json::to_string(&traces).unwrap();
+
+        assert_eq!(
+            serde_json::from_str::<Vec<TransactionCallTrace>>(&json).unwrap(),
+            traces
+        );
+    }
+}
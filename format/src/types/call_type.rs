@@ -0,0 +1,106 @@
+use crate::{Error, Result};
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::result::Result as StdResult;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CallType {
+    Call,
+    StaticCall,
+    DelegateCall,
+    CallCode,
+    Create,
+    Create2,
+    SelfDestruct,
+}
+
+impl FromStr for CallType {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "CALL" => Ok(Self::Call),
+            "STATICCALL" => Ok(Self::StaticCall),
+            "DELEGATECALL" => Ok(Self::DelegateCall),
+            "CALLCODE" => Ok(Self::CallCode),
+            "CREATE" => Ok(Self::Create),
+            "CREATE2" => Ok(Self::Create2),
+            "SELFDESTRUCT" => Ok(Self::SelfDestruct),
+            _ => Err(Error::UnknownCallType(s.to_owned())),
+        }
+    }
+}
+
+impl CallType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Call => "CALL",
+            Self::StaticCall => "STATICCALL",
+            Self::DelegateCall => "DELEGATECALL",
+            Self::CallCode => "CALLCODE",
+            Self::Create => "CREATE",
+            Self::Create2 => "CREATE2",
+            Self::SelfDestruct => "SELFDESTRUCT",
+        }
+    }
+}
+
+struct CallTypeVisitor;
+
+impl<'de> Visitor<'de> for CallTypeVisitor {
+    type Value = CallType;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("call type string")
+    }
+
+    fn visit_str<E>(self, value: &str) -> StdResult<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        CallType::from_str(value).map_err(|e| E::custom(e.to_string()))
+    }
+}
+
+impl<'de> Deserialize<'de> for CallType {
+    fn deserialize<D>(deserializer: D) -> StdResult<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(CallTypeVisitor)
+    }
+}
+
+impl Serialize for CallType {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CallType;
+    use serde_test::{assert_de_tokens, assert_tokens, Token};
+
+    #[test]
+    fn test_serde() {
+        assert_tokens(&CallType::Call, &[Token::Str("CALL")]);
+        assert_tokens(&CallType::StaticCall, &[Token::Str("STATICCALL")]);
+        assert_tokens(&CallType::DelegateCall, &[Token::Str("DELEGATECALL")]);
+        assert_tokens(&CallType::CallCode, &[Token::Str("CALLCODE")]);
+        assert_tokens(&CallType::Create, &[Token::Str("CREATE")]);
+        assert_tokens(&CallType::Create2, &[Token::Str("CREATE2")]);
+        assert_tokens(&CallType::SelfDestruct, &[Token::Str("SELFDESTRUCT")]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_de_unknown() {
+        assert_de_tokens(&CallType::Call, &[Token::Str("call")]);
+    }
+}
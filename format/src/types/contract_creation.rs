@@ -0,0 +1,173 @@
+use super::{
+    Address, BlockNumber, CallFrame, CallType, Hash, TransactionCallTrace, TransactionReceipt,
+    TransactionStatus,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContractCreation {
+    pub address: Address,
+    pub deployer: Address,
+    pub transaction_hash: Hash,
+    pub block_number: BlockNumber,
+    pub trace_address: Box<[u64]>,
+}
+
+impl TransactionReceipt {
+    /// Contract created by this transaction if it was a successful top level deployment.
+    ///
+    /// Pre-Byzantium receipts have no status and carry the contract address even if the
+    /// deployment failed, so they always return `None`. Use the call traces for those blocks.
+    pub fn contract_creation(&self) -> Option<ContractCreation> {
+        if self.status != Some(TransactionStatus::Success) {
+            return None;
+        }
+
+        Some(ContractCreation {
+            address: self.contract_address.clone()?,
+            deployer: self.from.clone(),
+            transaction_hash: self.transaction_hash.clone(),
+            block_number: self.block_number,
+            trace_address: Box::default(),
+        })
+    }
+}
+
+impl TransactionCallTrace {
+    /// Contracts created by CREATE and CREATE2 frames at any depth, in depth-first order.
+    ///
+    /// The deployer is the account that executed the frame, so contracts created by factories
+    /// point to the factory instead of the sender of the transaction.
+    pub fn contract_creations(&self, block_number: BlockNumber) -> Vec<ContractCreation> {
+        let mut creations = Vec::new();
+        collect_creations(
+            &self.tx_hash,
+            block_number,
+            &self.result,
+            &mut Vec::new(),
+            &mut creations,
+        );
+        creations
+    }
+}
+
+fn collect_creations(
+    tx_hash: &Hash,
+    block_number: BlockNumber,
+    frame: &CallFrame,
+    trace_address: &mut Vec<u64>,
+    creations: &mut Vec<ContractCreation>,
+) {
+    // A failed frame reverts the contracts created by it and all of its subcalls.
+    if frame.error.is_some() {
+        return;
+    }
+
+    if let (CallType::Create | CallType::Create2, Some(to)) = (frame.kind, &frame.to) {
+        creations.push(ContractCreation {
+            address: to.clone(),
+            deployer: frame.from.clone(),
+            transaction_hash: tx_hash.clone(),
+            block_number,
+            trace_address: trace_address.as_slice().into(),
+        });
+    }
+
+    for (i, call) in frame.calls.iter().enumerate() {
+        trace_address.push(i as u64);
+        collect_creations(tx_hash, block_number, call, trace_address, creations);
+        trace_address.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_util::{self, call_frame};
+    use crate::types::{
+        Address, BlockNumber, CallFrame, CallType, Hash, TransactionCallTrace, TransactionReceipt,
+        TransactionStatus,
+    };
+
+    fn frame(kind: CallType, from: u8, to: u8, calls: Vec<CallFrame>) -> CallFrame {
+        call_frame(kind, from, to, 0, calls)
+    }
+
+    fn receipt(
+        contract_address: Option<Address>,
+        status: Option<TransactionStatus>,
+    ) -> TransactionReceipt {
+        TransactionReceipt {
+            contract_address,
+            status,
+            ..test_util::receipt()
+        }
+    }
+
+    #[test]
+    fn test_receipt() {
+        let creation = receipt(
+            Some(Address::from([2; 20])),
+            Some(TransactionStatus::Success),
+        )
+        .contract_creation()
+        .unwrap();
+
+        assert_eq!(creation.address, Address::from([2; 20]));
+        assert_eq!(creation.deployer, Address::from([1; 20]));
+        assert_eq!(creation.transaction_hash, Hash::from([7; 32]));
+        assert_eq!(*creation.block_number, 11);
+        assert!(creation.trace_address.is_empty());
+
+        assert!(receipt(None, Some(TransactionStatus::Success))
+            .contract_creation()
+            .is_none());
+        assert!(receipt(
+            Some(Address::from([2; 20])),
+            Some(TransactionStatus::Failure)
+        )
+        .contract_creation()
+        .is_none());
+        // Pre-Byzantium
+        assert!(receipt(Some(Address::from([2; 20])), None)
+            .contract_creation()
+            .is_none());
+    }
+
+    #[test]
+    fn test_trace() {
+        let mut failed = frame(
+            CallType::Create,
+            3,
+            5,
+            vec![frame(CallType::Create, 5, 6, vec![])],
+        );
+        failed.error = Some("out of gas".to_owned());
+
+        let trace = TransactionCallTrace {
+            tx_hash: Hash::from([7; 32]),
+            result: frame(
+                CallType::Call,
+                1,
+                2,
+                vec![
+                    frame(CallType::StaticCall, 2, 4, vec![]),
+                    frame(
+                        CallType::Call,
+                        2,
+                        3,
+                        vec![frame(CallType::Create2, 3, 8, vec![]), failed],
+                    ),
+                ],
+            ),
+        };
+
+        let creations = trace.contract_creations(BlockNumber::from(11));
+
+        assert_eq!(creations.len(), 1);
+        assert_eq!(creations[0].address, Address::from([8; 20]));
+        assert_eq!(creations[0].deployer, Address::from([3; 20]));
+        assert_eq!(creations[0].trace_address.as_ref(), &[1, 0]);
+        assert_eq!(*creations[0].block_number, 11);
+    }
+}
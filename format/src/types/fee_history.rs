@@ -0,0 +1,339 @@
+use super::{BlockHeader, BlockNumber, Quantity, TransactionReceipt};
+use crate::{Error, Result};
+use serde::{Deserialize, Serialize};
+
+const ELASTICITY_MULTIPLIER: u128 = 2;
+const BASE_FEE_MAX_CHANGE_DENOMINATOR: u128 = 8;
+const INITIAL_BASE_FEE: u128 = 1_000_000_000;
+
+/// First block with a base fee on Ethereum mainnet.
+pub const MAINNET_LONDON_BLOCK: u64 = 12_965_000;
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeeHistory {
+    pub oldest_block: BlockNumber,
+    pub base_fee_per_gas: Vec<Quantity>,
+    pub gas_used_ratio: Vec<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reward: Option<Vec<Vec<Quantity>>>,
+}
+
+impl FeeHistory {
+    /// Builds the `eth_feeHistory` response for consecutive blocks given in ascending order.
+    ///
+    /// `reward_percentiles` follows the RPC parameter, rewards are omitted if it is `None` or
+    /// empty. The receipts of a block are only used for computing rewards. `london_block` is the
+    /// first block with a base fee on the chain, see [`next_base_fee`].
+    pub fn from_blocks<'a, I>(
+        blocks: I,
+        reward_percentiles: Option<&[f64]>,
+        london_block: u64,
+    ) -> Result<Self>
+    where
+        I: IntoIterator<Item = (&'a BlockHeader, &'a [TransactionReceipt])>,
+    {
+        let reward_percentiles = reward_percentiles.filter(|p| !p.is_empty());
+
+        if let Some(percentiles) = reward_percentiles {
+            check_percentiles(percentiles)?;
+        }
+
+        let mut history = FeeHistory {
+            reward: reward_percentiles.map(|_| Vec::new()),
+            ..Default::default()
+        };
+
+        let mut last = None;
+
+        for (header, receipts) in blocks {
+            if last.is_none() {
+                history.oldest_block = header.number;
+            }
+
+            let gas_limit = to_u128(&header.gas_limit)?;
+            let gas_used = to_u128(&header.gas_used)?;
+
+            history
+                .base_fee_per_gas
+                .push(header.base_fee_per_gas.clone().unwrap_or_default());
+            history.gas_used_ratio.push(if gas_limit == 0 {
+                0.0
+            } else {
+                gas_used as f64 / gas_limit as f64
+            });
+
+            if let (Some(rewards), Some(percentiles)) = (&mut history.reward, reward_percentiles) {
+                rewards.push(block_rewards(header, receipts, percentiles)?);
+            }
+
+            last = Some(header);
+        }
+
+        if let Some(header) = last {
+            history
+                .base_fee_per_gas
+                .push(next_base_fee(header, london_block)?);
+        }
+
+        Ok(history)
+    }
+}
+
+/// Base fee of the block following `header` according to EIP-1559.
+///
+/// `london_block` is the first block with a base fee on the chain. Returns zero if the following
+/// block is before it and the initial base fee of 1 gwei if it is the fork block.
+pub fn next_base_fee(header: &BlockHeader, london_block: u64) -> Result<Quantity> {
+    let next_block = header.number.saturating_add(1);
+    if next_block < london_block {
+        return Ok(Quantity::default());
+    }
+
+    let quantity = match &header.base_fee_per_gas {
+        Some(base_fee) if next_block > london_block => base_fee,
+        _ => return Ok(from_u128(INITIAL_BASE_FEE)),
+    };
+    let base_fee = to_u128(quantity)?;
+    let gas_target = to_u128(&header.gas_limit)? / ELASTICITY_MULTIPLIER;
+    let gas_used = to_u128(&header.gas_used)?;
+
+    if gas_target == 0 || gas_used == gas_target {
+        return Ok(from_u128(base_fee));
+    }
+
+    let delta = |gas_diff: u128| {
+        base_fee
+            .checked_mul(gas_diff)
+            .map(|fee| fee / gas_target / BASE_FEE_MAX_CHANGE_DENOMINATOR)
+    };
+
+    let next = if gas_used > gas_target {
+        delta(gas_used - gas_target).and_then(|delta| base_fee.checked_add(delta.max(1)))
+    } else {
+        delta(gas_target - gas_used).map(|delta| base_fee.saturating_sub(delta))
+    };
+
+    next.map(from_u128)
+        .ok_or_else(|| Error::QuantityOverflow(quantity.clone()))
+}
+
+// Same selection as geth: transactions are sorted by priority fee and the reward for a
+// percentile is the fee of the transaction at which the cumulative gas used reaches that
+// percentile of the block gas used.
+fn block_rewards(
+    header: &BlockHeader,
+    receipts: &[TransactionReceipt],
+    percentiles: &[f64],
+) -> Result<Vec<Quantity>> {
+    if receipts.is_empty() {
+        return Ok(vec![Quantity::default(); percentiles.len()]);
+    }
+
+    let base_fee = match &header.base_fee_per_gas {
+        Some(base_fee) => to_u128(base_fee)?,
+        None => 0,
+    };
+
+    let mut txs = receipts
+        .iter()
+        .map(|receipt| {
+            let priority_fee = to_u128(&receipt.effective_gas_price)?.saturating_sub(base_fee);
+            Ok((priority_fee, to_u128(&receipt.gas_used)?))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    txs.sort_unstable_by_key(|(priority_fee, _)| *priority_fee);
+
+    let block_gas_used = to_u128(&header.gas_used)?;
+
+    let mut rewards = Vec::with_capacity(percentiles.len());
+    let mut idx = 0;
+    let mut sum_gas_used = txs[0].1;
+
+    for percentile in percentiles {
+        let threshold = (block_gas_used as f64 * percentile / 100.0) as u128;
+
+        while sum_gas_used < threshold && idx < txs.len() - 1 {
+            idx += 1;
+            sum_gas_used = sum_gas_used.saturating_add(txs[idx].1);
+        }
+
+        rewards.push(from_u128(txs[idx].0));
+    }
+
+    Ok(rewards)
+}
+
+fn check_percentiles(percentiles: &[f64]) -> Result<()> {
+    let in_range = percentiles.iter().all(|p| (0.0..=100.0).contains(p));
+    let increasing = percentiles.windows(2).all(|w| w[0] <= w[1]);
+
+    if !in_range || !increasing {
+        return Err(Error::InvalidRewardPercentiles(percentiles.to_vec()));
+    }
+
+    Ok(())
+}
+
+fn to_u128(quantity: &Quantity) -> Result<u128> {
+    if quantity.len() > 16 {
+        return Err(Error::QuantityOverflow(quantity.clone()));
+    }
+
+    let mut buf = [0; 16];
+    buf[16 - quantity.len()..].copy_from_slice(quantity);
+
+    Ok(u128::from_be_bytes(buf))
+}
+
+fn from_u128(val: u128) -> Quantity {
+    let buf = val.to_be_bytes();
+
+    match buf.iter().position(|b| *b != 0) {
+        Some(idx) => Quantity::from(&buf[idx..]),
+        None => Quantity::default(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{from_u128, next_base_fee, to_u128, FeeHistory, MAINNET_LONDON_BLOCK};
+    use crate::test_util;
+    use crate::types::{BlockHeader, Quantity, TransactionReceipt, TransactionType};
+
+    fn header(number: u64, base_fee: Option<u128>, gas_used: u128) -> BlockHeader {
+        BlockHeader {
+            number: number.into(),
+            base_fee_per_gas: base_fee.map(from_u128),
+            gas_limit: from_u128(30_000_000),
+            gas_used: from_u128(gas_used),
+            ..Default::default()
+        }
+    }
+
+    fn receipt(effective_gas_price: u128, gas_used: u128) -> TransactionReceipt {
+        TransactionReceipt {
+            effective_gas_price: from_u128(effective_gas_price),
+            gas_used: from_u128(gas_used),
+            kind: TransactionType::DynamicFee,
+            ..test_util::receipt()
+        }
+    }
+
+    #[test]
+    fn test_quantity_conversion() {
+        assert_eq!(from_u128(0), Quantity::default());
+        assert_eq!(from_u128(0x4200), Quantity::from([0x42, 0]));
+        assert_eq!(to_u128(&Quantity::from([0x42, 0])).unwrap(), 0x4200);
+        assert!(to_u128(&Quantity::from([1; 17])).is_err());
+    }
+
+    #[test]
+    fn test_next_base_fee() {
+        let next = |gas_used| to_u128(&next_base_fee(&header(1, Some(1000), gas_used), 0).unwrap());
+
+        assert_eq!(next(15_000_000).unwrap(), 1000);
+        assert_eq!(next(30_000_000).unwrap(), 1125);
+        assert_eq!(next(0).unwrap(), 875);
+        // Increase is at least 1 wei.
+        assert_eq!(next(15_000_001).unwrap(), 1001);
+
+        let mut huge = header(1, Some(u128::MAX / 2), 30_000_000);
+        assert!(next_base_fee(&huge, 0).is_err());
+        huge.gas_limit = from_u128(u128::MAX);
+        huge.gas_used = from_u128(u128::MAX);
+        assert!(next_base_fee(&huge, 0).is_err());
+    }
+
+    #[test]
+    fn test_next_base_fee_london() {
+        let london = MAINNET_LONDON_BLOCK;
+
+        assert_eq!(
+            next_base_fee(&header(london - 2, None, 0), london).unwrap(),
+            Quantity::default()
+        );
+        assert_eq!(
+            next_base_fee(&header(london - 1, None, 0), london).unwrap(),
+            from_u128(1_000_000_000)
+        );
+        assert_eq!(
+            next_base_fee(&header(london, Some(1_000_000_000), 30_000_000), london).unwrap(),
+            from_u128(1_125_000_000)
+        );
+    }
+
+    #[test]
+    fn test_header_base_fee_serde() {
+        let json = serde_json::to_value(header(1, None, 0)).unwrap();
+        assert!(json.get("baseFeePerGas").is_none());
+        let parsed: BlockHeader = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed.base_fee_per_gas, None);
+
+        let json = serde_json::to_value(header(1, Some(1000), 0)).unwrap();
+        assert_eq!(json["baseFeePerGas"], "0x3e8");
+    }
+
+    #[test]
+    fn test_fee_history() {
+        let blocks = [
+            (header(10, Some(100), 15_000_000), vec![]),
+            (
+                header(11, Some(100), 30_000_000),
+                vec![
+                    receipt(130, 10_000_000),
+                    receipt(110, 10_000_000),
+                    receipt(120, 10_000_000),
+                ],
+            ),
+        ];
+
+        let history = FeeHistory::from_blocks(
+            blocks.iter().map(|(h, r)| (h, r.as_slice())),
+            Some(&[0.0, 50.0, 100.0]),
+            0,
+        )
+        .unwrap();
+
+        assert_eq!(*history.oldest_block, 10);
+        assert_eq!(
+            history.base_fee_per_gas,
+            vec![from_u128(100), from_u128(100), from_u128(112)]
+        );
+        assert_eq!(history.gas_used_ratio, vec![0.5, 1.0]);
+        assert_eq!(
+            history.reward,
+            Some(vec![
+                vec![Quantity::default(); 3],
+                vec![from_u128(10), from_u128(20), from_u128(30)],
+            ])
+        );
+    }
+
+    #[test]
+    fn test_fee_history_without_rewards() {
+        let block = header(10, Some(100), 15_000_000);
+        let history = FeeHistory::from_blocks([(&block, [].as_slice())], None, 0).unwrap();
+
+        assert_eq!(history.reward, None);
+        assert_eq!(history.base_fee_per_gas.len(), 2);
+
+        let history = FeeHistory::from_blocks([(&block, [].as_slice())], Some(&[]), 0).unwrap();
+        assert_eq!(history.reward, None);
+        assert!(serde_json::to_value(&history)
+            .unwrap()
+            .get("reward")
+            .is_none());
+    }
+
+    #[test]
+    fn test_invalid_percentiles() {
+        let block = header(10, Some(100), 15_000_000);
+
+        for percentiles in [[50.0, 10.0], [-1.0, 10.0], [10.0, 101.0]] {
+            assert!(
+                FeeHistory::from_blocks([(&block, [].as_slice())], Some(&percentiles), 0).is_err()
+            );
+        }
+    }
+}
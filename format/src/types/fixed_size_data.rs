@@ -5,7 +5,16 @@ use std::fmt;
 use std::result::Result as StdResult;
 
 #[derive(
-    Debug, Clone, PartialEq, Eq, Hash, derive_more::From, derive_more::Into, derive_more::Deref,
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    derive_more::From,
+    derive_more::Into,
+    derive_more::Deref,
 )]
 pub struct FixedSizeData<const N: usize>(Box<[u8; N]>);
 
@@ -0,0 +1,172 @@
+use super::{Address, CallFrame, CallType, Hash, Quantity, TransactionCallTrace};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InternalTransfer {
+    pub transaction_hash: Hash,
+    pub trace_address: Box<[u64]>,
+    pub kind: CallType,
+    pub from: Address,
+    pub to: Address,
+    pub value: Quantity,
+}
+
+impl TransactionCallTrace {
+    /// Value-carrying call frames below the top level frame, in depth-first order.
+    pub fn internal_transfers(&self) -> Vec<InternalTransfer> {
+        let mut transfers = Vec::new();
+
+        if self.result.error.is_some() {
+            return transfers;
+        }
+
+        let mut trace_address = Vec::new();
+        for (i, call) in self.result.calls.iter().enumerate() {
+            trace_address.push(i as u64);
+            collect_transfers(&self.tx_hash, call, &mut trace_address, &mut transfers);
+            trace_address.pop();
+        }
+
+        transfers
+    }
+}
+
+fn collect_transfers(
+    tx_hash: &Hash,
+    frame: &CallFrame,
+    trace_address: &mut Vec<u64>,
+    transfers: &mut Vec<InternalTransfer>,
+) {
+    // A failed frame reverts the value moved by it and all of its subcalls.
+    if frame.error.is_some() {
+        return;
+    }
+
+    // delegatecall and staticcall frames don't move any value even if geth reports one. callcode
+    // sends the value from the caller back to itself, `to` is only the address of the code.
+    let moves_value = !matches!(
+        frame.kind,
+        CallType::DelegateCall | CallType::StaticCall | CallType::CallCode
+    );
+
+    if let (true, Some(to), Some(value)) = (moves_value, &frame.to, &frame.value) {
+        if *value != Quantity::default() {
+            transfers.push(InternalTransfer {
+                transaction_hash: tx_hash.clone(),
+                trace_address: trace_address.as_slice().into(),
+                kind: frame.kind,
+                from: frame.from.clone(),
+                to: to.clone(),
+                value: value.clone(),
+            });
+        }
+    }
+
+    for (i, call) in frame.calls.iter().enumerate() {
+        trace_address.push(i as u64);
+        collect_transfers(tx_hash, call, trace_address, transfers);
+        trace_address.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_util::call_frame;
+    use crate::types::{CallFrame, CallType, Hash, Quantity, TransactionCallTrace};
+
+    fn frame(kind: CallType, to: u8, value: u8, calls: Vec<CallFrame>) -> CallFrame {
+        call_frame(kind, 1, to, value, calls)
+    }
+
+    fn trace(result: CallFrame) -> TransactionCallTrace {
+        TransactionCallTrace {
+            tx_hash: Hash::from([7; 32]),
+            result,
+        }
+    }
+
+    fn summary(trace: &TransactionCallTrace) -> Vec<(Vec<u64>, u8)> {
+        trace
+            .internal_transfers()
+            .into_iter()
+            .map(|t| (t.trace_address.into_vec(), t.to[0]))
+            .collect()
+    }
+
+    #[test]
+    fn test_top_level_frame_is_skipped() {
+        let trace = trace(frame(CallType::Call, 2, 5, vec![]));
+
+        assert!(trace.internal_transfers().is_empty());
+    }
+
+    #[test]
+    fn test_nested_transfers() {
+        let trace = trace(frame(
+            CallType::Call,
+            2,
+            5,
+            vec![
+                frame(
+                    CallType::Call,
+                    3,
+                    1,
+                    vec![frame(
+                        CallType::Call,
+                        4,
+                        0,
+                        vec![frame(CallType::Create2, 5, 1, vec![])],
+                    )],
+                ),
+                frame(CallType::DelegateCall, 6, 1, vec![]),
+                frame(CallType::SelfDestruct, 8, 2, vec![]),
+            ],
+        ));
+
+        assert_eq!(
+            summary(&trace),
+            vec![(vec![0], 3), (vec![0, 0, 0], 5), (vec![2], 8)]
+        );
+
+        let transfers = trace.internal_transfers();
+        assert_eq!(transfers[2].kind, CallType::SelfDestruct);
+        assert_eq!(transfers[2].value, Quantity::from([2]));
+        assert_eq!(transfers[2].transaction_hash, Hash::from([7; 32]));
+    }
+
+    #[test]
+    fn test_callcode_is_skipped() {
+        let trace = trace(frame(
+            CallType::Call,
+            2,
+            0,
+            vec![
+                frame(CallType::CallCode, 3, 1, vec![]),
+                frame(CallType::Call, 4, 1, vec![]),
+            ],
+        ));
+
+        assert_eq!(summary(&trace), vec![(vec![1], 4)]);
+    }
+
+    #[test]
+    fn test_failed_frames_are_skipped() {
+        let mut failed = frame(
+            CallType::Call,
+            3,
+            1,
+            vec![frame(CallType::Call, 4, 1, vec![])],
+        );
+        failed.error = Some("execution reverted".to_owned());
+
+        let trace = trace(frame(
+            CallType::Call,
+            2,
+            0,
+            vec![failed, frame(CallType::Call, 5, 1, vec![])],
+        ));
+
+        assert_eq!(summary(&trace), vec![(vec![1], 5)]);
+    }
+}
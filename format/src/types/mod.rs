@@ -1,16 +1,48 @@
 use arrayvec::ArrayVec;
 use serde::{Deserialize, Serialize};
 
+mod block_id;
+mod block_number_or_tag;
+mod block_tag;
+mod block_transactions;
+mod call_frame;
+mod call_type;
+mod contract_creation;
 mod data;
+mod fee_history;
 mod fixed_size_data;
+mod internal_transfer;
 mod quantity;
+mod state_diff;
+mod sync_status;
+mod token_standard;
+mod token_transfer;
+mod trace;
+mod trace_call_type;
+mod trace_type;
 mod transaction_status;
 mod transaction_type;
 mod uint;
 
+pub use block_id::BlockId;
+pub use block_number_or_tag::BlockNumberOrTag;
+pub use block_tag::BlockTag;
+pub use block_transactions::BlockTransactions;
+pub use call_frame::{CallFrame, TransactionCallTrace};
+pub use call_type::CallType;
+pub use contract_creation::ContractCreation;
 pub use data::Data;
+pub use fee_history::{next_base_fee, FeeHistory, MAINNET_LONDON_BLOCK};
 pub use fixed_size_data::FixedSizeData;
+pub use internal_transfer::InternalTransfer;
 pub use quantity::Quantity;
+pub use state_diff::{AccountState, StateDiff, TransactionStateDiff};
+pub use sync_status::{SyncProgress, SyncStatus};
+pub use token_standard::TokenStandard;
+pub use token_transfer::TokenTransfer;
+pub use trace::{Trace, TraceAction, TraceResult};
+pub use trace_call_type::TraceCallType;
+pub use trace_type::TraceType;
 pub use transaction_status::TransactionStatus;
 pub use transaction_type::TransactionType;
 
@@ -28,13 +60,16 @@ pub struct BlockHeader {
     pub receipts_root: Hash,
     pub miner: Address,
     pub difficulty: Quantity,
-    pub total_difficulty: Quantity,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_difficulty: Option<Quantity>,
     pub extra_data: Data,
     pub size: Quantity,
     pub gas_limit: Quantity,
     pub gas_used: Quantity,
     pub timestamp: Quantity,
     pub uncles: Box<[Hash]>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_fee_per_gas: Option<Quantity>,
 }
 
 #[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -42,7 +77,7 @@ pub struct BlockHeader {
 pub struct Block {
     #[serde(flatten)]
     pub header: BlockHeader,
-    pub transactions: Box<[Transaction]>,
+    pub transactions: BlockTransactions,
 }
 
 #[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -95,7 +130,7 @@ pub struct Log {
     pub block_hash: Hash,
     pub block_number: BlockNumber,
     pub address: Address,
-    pub data: Box<[LogArgument]>,
+    pub data: Data,
     pub topics: ArrayVec<LogArgument, 4>,
 }
 
@@ -104,6 +139,8 @@ pub type LogArgument = FixedSizeData<32>;
 pub type Address = FixedSizeData<20>;
 pub type Nonce = FixedSizeData<8>;
 pub type BloomFilter = FixedSizeData<256>;
+pub type StorageSlot = FixedSizeData<32>;
+pub type StorageValue = FixedSizeData<32>;
 pub type BlockNumber = uint::UInt;
 pub type TransactionIndex = uint::UInt;
 pub type LogIndex = uint::UInt;
@@ -0,0 +1,124 @@
+use super::{Address, Data, Hash, Quantity, StorageSlot, StorageValue};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountState {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub balance: Option<Quantity>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<Data>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub storage: BTreeMap<StorageSlot, StorageValue>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StateDiff {
+    pub pre: BTreeMap<Address, AccountState>,
+    pub post: BTreeMap<Address, AccountState>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionStateDiff {
+    pub tx_hash: Hash,
+    pub result: StateDiff,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TransactionStateDiff;
+    use crate::types::{Address, Data, Quantity, StorageSlot, StorageValue};
+    use hex_literal::hex;
+
+    // debug_traceBlockByNumber with {"tracer": "prestateTracer", "tracerConfig": {"diffMode": true}}
+    const BLOCK_DIFF: &str = r#"[
+        {
+            "txHash": "0x4d2e8c31a1fe3bd0d4c8a9f5c1d1b3c5e0e8f4b4a77d1b2e3c4d5e6f708192a3",
+            "result": {
+                "post": {
+                    "0x35a9f94af726f07b5162df7e828cc9dc8439e7d0": {
+                        "nonce": 1135
+                    },
+                    "0xc8ba32cab1757528daf49033e3673fae77dcf05d": {
+                        "balance": "0x7a48429e177130a",
+                        "storage": {
+                            "0x0000000000000000000000000000000000000000000000000000000000000001": "0x000000000000000000000000000000000000000000000000000000000000002a"
+                        }
+                    }
+                },
+                "pre": {
+                    "0x35a9f94af726f07b5162df7e828cc9dc8439e7d0": {
+                        "balance": "0x7a48734599f7284",
+                        "nonce": 1134
+                    },
+                    "0xc8ba32cab1757528daf49033e3673fae77dcf05d": {
+                        "balance": "0x7a48429e0000000",
+                        "code": "0x6080604052",
+                        "nonce": 1,
+                        "storage": {
+                            "0x0000000000000000000000000000000000000000000000000000000000000001": "0x0000000000000000000000000000000000000000000000000000000000000000"
+                        }
+                    }
+                }
+            }
+        }
+    ]"#;
+
+    #[test]
+    fn test_de_prestate_diff() {
+        let diffs: Vec<TransactionStateDiff> = serde_json::from_str(BLOCK_DIFF).unwrap();
+        assert_eq!(diffs.len(), 1);
+
+        let diff = &diffs[0].result;
+        let sender = Address::from(hex!("35a9f94af726f07b5162df7e828cc9dc8439e7d0"));
+        let contract = Address::from(hex!("c8ba32cab1757528daf49033e3673fae77dcf05d"));
+        let slot = StorageSlot::from(hex!(
+            "0000000000000000000000000000000000000000000000000000000000000001"
+        ));
+
+        let pre = &diff.pre[&sender];
+        assert_eq!(pre.nonce, Some(1134));
+        assert_eq!(pre.balance, Some(Quantity::from(hex!("07a48734599f7284"))));
+        assert_eq!(pre.code, None);
+        assert!(pre.storage.is_empty());
+
+        let post = &diff.post[&sender];
+        assert_eq!(post.nonce, Some(1135));
+        assert_eq!(post.balance, None);
+
+        let pre = &diff.pre[&contract];
+        assert_eq!(pre.code, Some(Data::from(hex!("6080604052"))));
+        assert_eq!(pre.storage[&slot], StorageValue::default());
+
+        let post = &diff.post[&contract];
+        assert_eq!(post.nonce, None);
+        assert_eq!(post.code, None);
+        assert_eq!(
+            post.storage[&slot],
+            StorageValue::from(hex!(
+                "000000000000000000000000000000000000000000000000000000000000002a"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_serde_roundtrip() {
+        let diffs: Vec<TransactionStateDiff> = serde_json::from_str(BLOCK_DIFF).unwrap();
+        let json = serde_json::to_string(&diffs).unwrap();
+
+        assert_eq!(
+            serde_json::from_str::<Vec<TransactionStateDiff>>(&json).unwrap(),
+            diffs
+        );
+        // Missing fields stay missing, like in the tracer output.
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&json).unwrap(),
+            serde_json::from_str::<serde_json::Value>(BLOCK_DIFF).unwrap()
+        );
+    }
+}
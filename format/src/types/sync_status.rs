@@ -0,0 +1,133 @@
+use super::BlockNumber;
+use serde::de::{self, value::MapAccessDeserializer, MapAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::result::Result as StdResult;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncProgress {
+    pub starting_block: BlockNumber,
+    pub current_block: BlockNumber,
+    pub highest_block: BlockNumber,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SyncStatus {
+    Synced,
+    Syncing(SyncProgress),
+}
+
+struct SyncStatusVisitor;
+
+impl<'de> Visitor<'de> for SyncStatusVisitor {
+    type Value = SyncStatus;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("false or sync progress object")
+    }
+
+    fn visit_bool<E>(self, value: bool) -> StdResult<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        if value {
+            return Err(E::invalid_value(de::Unexpected::Bool(value), &self));
+        }
+
+        Ok(SyncStatus::Synced)
+    }
+
+    fn visit_map<A>(self, map: A) -> StdResult<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        SyncProgress::deserialize(MapAccessDeserializer::new(map)).map(SyncStatus::Syncing)
+    }
+}
+
+impl<'de> Deserialize<'de> for SyncStatus {
+    fn deserialize<D>(deserializer: D) -> StdResult<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(SyncStatusVisitor)
+    }
+}
+
+impl Serialize for SyncStatus {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::Synced => serializer.serialize_bool(false),
+            Self::Syncing(progress) => progress.serialize(serializer),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SyncStatus;
+    use crate::types::{BlockNumber, SyncProgress};
+    use serde_test::{assert_de_tokens, assert_de_tokens_error, assert_tokens, Token};
+
+    fn progress() -> SyncProgress {
+        SyncProgress {
+            starting_block: BlockNumber::from(1),
+            current_block: BlockNumber::from(16),
+            highest_block: BlockNumber::from(32),
+        }
+    }
+
+    #[test]
+    fn test_serde_synced() {
+        assert_tokens(&SyncStatus::Synced, &[Token::Bool(false)]);
+    }
+
+    #[test]
+    fn test_serde_syncing() {
+        assert_tokens(
+            &SyncStatus::Syncing(progress()),
+            &[
+                Token::Struct {
+                    name: "SyncProgress",
+                    len: 3,
+                },
+                Token::Str("startingBlock"),
+                Token::Str("0x1"),
+                Token::Str("currentBlock"),
+                Token::Str("0x10"),
+                Token::Str("highestBlock"),
+                Token::Str("0x20"),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_de_syncing_map() {
+        assert_de_tokens(
+            &SyncStatus::Syncing(progress()),
+            &[
+                Token::Map { len: Some(3) },
+                Token::Str("startingBlock"),
+                Token::Str("0x1"),
+                Token::Str("currentBlock"),
+                Token::Str("0x10"),
+                Token::Str("highestBlock"),
+                Token::Str("0x20"),
+                Token::MapEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_de_true() {
+        assert_de_tokens_error::<SyncStatus>(
+            &[Token::Bool(true)],
+            "invalid value: boolean `true`, expected false or sync progress object",
+        );
+    }
+}
@@ -0,0 +1,90 @@
+use crate::{Error, Result};
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::result::Result as StdResult;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenStandard {
+    Erc20,
+    Erc721,
+    Erc1155,
+}
+
+impl FromStr for TokenStandard {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "erc20" => Ok(Self::Erc20),
+            "erc721" => Ok(Self::Erc721),
+            "erc1155" => Ok(Self::Erc1155),
+            _ => Err(Error::UnknownTokenStandard(s.to_owned())),
+        }
+    }
+}
+
+impl TokenStandard {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Erc20 => "erc20",
+            Self::Erc721 => "erc721",
+            Self::Erc1155 => "erc1155",
+        }
+    }
+}
+
+struct TokenStandardVisitor;
+
+impl<'de> Visitor<'de> for TokenStandardVisitor {
+    type Value = TokenStandard;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("token standard string")
+    }
+
+    fn visit_str<E>(self, value: &str) -> StdResult<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        TokenStandard::from_str(value).map_err(|e| E::custom(e.to_string()))
+    }
+}
+
+impl<'de> Deserialize<'de> for TokenStandard {
+    fn deserialize<D>(deserializer: D) -> StdResult<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(TokenStandardVisitor)
+    }
+}
+
+impl Serialize for TokenStandard {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TokenStandard;
+    use serde_test::{assert_de_tokens, assert_tokens, Token};
+
+    #[test]
+    fn test_serde() {
+        assert_tokens(&TokenStandard::Erc20, &[Token::Str("erc20")]);
+        assert_tokens(&TokenStandard::Erc721, &[Token::Str("erc721")]);
+        assert_tokens(&TokenStandard::Erc1155, &[Token::Str("erc1155")]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_de_unknown() {
+        assert_de_tokens(&TokenStandard::Erc20, &[Token::Str("ERC20")]);
+    }
+}
@@ -0,0 +1,384 @@
+use super::{
+    Address, BlockNumber, Hash, Log, LogArgument, LogIndex, Quantity, TokenStandard,
+    TransactionIndex,
+};
+use hex_literal::hex;
+use serde::{Deserialize, Serialize};
+
+// keccak256("Transfer(address,address,uint256)")
+const TRANSFER_TOPIC: [u8; 32] =
+    hex!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef");
+// keccak256("TransferSingle(address,address,address,uint256,uint256)")
+const TRANSFER_SINGLE_TOPIC: [u8; 32] =
+    hex!("c3d58168c5ae7397731d063d5bbf3d657854427343f4c083240f7aacaa2d0f62");
+// keccak256("TransferBatch(address,address,address,uint256[],uint256[])")
+const TRANSFER_BATCH_TOPIC: [u8; 32] =
+    hex!("4a39dc06d4c0dbc64b70af90fd698a233a518aa5d07e595d983b8c0526c8f7fb");
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenTransfer {
+    pub standard: TokenStandard,
+    pub token: Address,
+    pub operator: Option<Address>,
+    pub from: Address,
+    pub to: Address,
+    pub token_id: Option<Quantity>,
+    pub amount: Quantity,
+    pub block_hash: Hash,
+    pub block_number: BlockNumber,
+    pub transaction_hash: Hash,
+    pub transaction_index: TransactionIndex,
+    pub log_index: LogIndex,
+    pub batch_index: Option<u64>,
+}
+
+impl TokenTransfer {
+    /// Decodes the standard ERC-20/ERC-721 `Transfer` and ERC-1155 `TransferSingle`/`TransferBatch`
+    /// events.
+    ///
+    /// Returns an empty list if the log isn't one of these events or doesn't follow the standard
+    /// layout for it.
+    pub fn from_log(log: &Log) -> Vec<TokenTransfer> {
+        decode_log(log).unwrap_or_default()
+    }
+}
+
+fn decode_log(log: &Log) -> Option<Vec<TokenTransfer>> {
+    let topic0: &[u8; 32] = log.topics.first()?;
+
+    let decode = match *topic0 {
+        TRANSFER_TOPIC => decode_transfer,
+        TRANSFER_SINGLE_TOPIC => decode_transfer_single,
+        TRANSFER_BATCH_TOPIC => decode_transfer_batch,
+        _ => return None,
+    };
+
+    decode(log, &data_words(&log.data)?)
+}
+
+fn decode_transfer(log: &Log, data: &[LogArgument]) -> Option<Vec<TokenTransfer>> {
+    let from = decode_address(log.topics.get(1)?)?;
+    let to = decode_address(log.topics.get(2)?)?;
+
+    // ERC-20 and ERC-721 share the same event signature, they only differ in whether the last
+    // argument is indexed.
+    let transfer = match (log.topics.len(), data) {
+        (3, [amount]) => new_transfer(log, TokenStandard::Erc20, None, from, to, None, amount),
+        (4, []) => new_transfer(
+            log,
+            TokenStandard::Erc721,
+            None,
+            from,
+            to,
+            Some(&log.topics[3]),
+            &LogArgument::from(one_word()),
+        ),
+        _ => return None,
+    };
+
+    Some(vec![transfer])
+}
+
+fn decode_transfer_single(log: &Log, data: &[LogArgument]) -> Option<Vec<TokenTransfer>> {
+    if log.topics.len() != 4 {
+        return None;
+    }
+
+    let operator = decode_address(&log.topics[1])?;
+    let from = decode_address(&log.topics[2])?;
+    let to = decode_address(&log.topics[3])?;
+
+    let [id, amount] = data else {
+        return None;
+    };
+
+    Some(vec![new_transfer(
+        log,
+        TokenStandard::Erc1155,
+        Some(operator),
+        from,
+        to,
+        Some(id),
+        amount,
+    )])
+}
+
+fn decode_transfer_batch(log: &Log, data: &[LogArgument]) -> Option<Vec<TokenTransfer>> {
+    if log.topics.len() != 4 {
+        return None;
+    }
+
+    let operator = decode_address(&log.topics[1])?;
+    let from = decode_address(&log.topics[2])?;
+    let to = decode_address(&log.topics[3])?;
+
+    let ids = decode_word_array(data, data.first()?)?;
+    let amounts = decode_word_array(data, data.get(1)?)?;
+
+    if ids.len() != amounts.len() {
+        return None;
+    }
+
+    let transfers = ids
+        .iter()
+        .zip(amounts.iter())
+        .enumerate()
+        .map(|(i, (id, amount))| TokenTransfer {
+            batch_index: Some(i as u64),
+            ..new_transfer(
+                log,
+                TokenStandard::Erc1155,
+                Some(operator.clone()),
+                from.clone(),
+                to.clone(),
+                Some(id),
+                amount,
+            )
+        })
+        .collect();
+
+    Some(transfers)
+}
+
+fn new_transfer(
+    log: &Log,
+    standard: TokenStandard,
+    operator: Option<Address>,
+    from: Address,
+    to: Address,
+    token_id: Option<&LogArgument>,
+    amount: &LogArgument,
+) -> TokenTransfer {
+    TokenTransfer {
+        standard,
+        token: log.address.clone(),
+        operator,
+        from,
+        to,
+        token_id: token_id.map(decode_quantity),
+        amount: decode_quantity(amount),
+        block_hash: log.block_hash.clone(),
+        block_number: log.block_number,
+        transaction_hash: log.transaction_hash.clone(),
+        transaction_index: log.transaction_index,
+        log_index: log.log_index,
+        batch_index: None,
+    }
+}
+
+fn one_word() -> [u8; 32] {
+    let mut word = [0; 32];
+    word[31] = 1;
+    word
+}
+
+// Splits ABI encoded log data into 32 byte words, `None` if the data isn't word aligned.
+fn data_words(data: &[u8]) -> Option<Vec<LogArgument>> {
+    if !data.len().is_multiple_of(32) {
+        return None;
+    }
+
+    data.chunks(32)
+        .map(|word| LogArgument::try_from(word).ok())
+        .collect()
+}
+
+fn decode_address(word: &LogArgument) -> Option<Address> {
+    let (padding, addr) = word.split_at(12);
+
+    if padding.iter().any(|b| *b != 0) {
+        return None;
+    }
+
+    Address::try_from(addr).ok()
+}
+
+fn decode_quantity(word: &LogArgument) -> Quantity {
+    match word.iter().position(|b| *b != 0) {
+        Some(idx) => Quantity::from(&word[idx..]),
+        None => Quantity::default(),
+    }
+}
+
+fn decode_usize(word: &LogArgument) -> Option<usize> {
+    let (high, low) = word.split_at(24);
+
+    if high.iter().any(|b| *b != 0) {
+        return None;
+    }
+
+    usize::try_from(u64::from_be_bytes(low.try_into().unwrap())).ok()
+}
+
+// Decodes an ABI encoded uint256[] whose byte offset into the data is given by `offset`.
+fn decode_word_array<'a>(
+    data: &'a [LogArgument],
+    offset: &LogArgument,
+) -> Option<&'a [LogArgument]> {
+    let offset = decode_usize(offset)?;
+
+    if offset % 32 != 0 {
+        return None;
+    }
+
+    let start = offset / 32;
+    let len = decode_usize(data.get(start)?)?;
+    let end = start.checked_add(1)?.checked_add(len)?;
+
+    data.get(start + 1..end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TokenTransfer, TRANSFER_BATCH_TOPIC, TRANSFER_SINGLE_TOPIC, TRANSFER_TOPIC};
+    use crate::test_util::addr_word;
+    use crate::types::{Address, Log, LogArgument, Quantity, TokenStandard};
+
+    fn word(val: u64) -> LogArgument {
+        let mut buf = [0; 32];
+        buf[24..].copy_from_slice(&val.to_be_bytes());
+        LogArgument::from(buf)
+    }
+
+    fn log(topics: Vec<LogArgument>, data: Vec<LogArgument>) -> Log {
+        Log {
+            address: Address::from([9; 20]),
+            topics: topics.into_iter().collect(),
+            data: data
+                .iter()
+                .flat_map(|word| word.iter().copied())
+                .collect::<Vec<u8>>()
+                .into(),
+            log_index: 3.into(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_erc20() {
+        let transfers = TokenTransfer::from_log(&log(
+            vec![TRANSFER_TOPIC.into(), addr_word(1), addr_word(2)],
+            vec![word(0x4200)],
+        ));
+
+        assert_eq!(transfers.len(), 1);
+        let t = &transfers[0];
+        assert_eq!(t.standard, TokenStandard::Erc20);
+        assert_eq!(t.token, Address::from([9; 20]));
+        assert_eq!(t.from, Address::from([1; 20]));
+        assert_eq!(t.to, Address::from([2; 20]));
+        assert_eq!(t.amount, Quantity::from([0x42, 0x00]));
+        assert_eq!(t.token_id, None);
+        assert_eq!(t.operator, None);
+        assert_eq!(*t.log_index, 3);
+    }
+
+    #[test]
+    fn test_erc721() {
+        let transfers = TokenTransfer::from_log(&log(
+            vec![TRANSFER_TOPIC.into(), addr_word(1), addr_word(2), word(7)],
+            vec![],
+        ));
+
+        assert_eq!(transfers.len(), 1);
+        let t = &transfers[0];
+        assert_eq!(t.standard, TokenStandard::Erc721);
+        assert_eq!(t.token_id, Some(Quantity::from([7])));
+        assert_eq!(t.amount, Quantity::from([1]));
+    }
+
+    #[test]
+    fn test_erc1155_single() {
+        let transfers = TokenTransfer::from_log(&log(
+            vec![
+                TRANSFER_SINGLE_TOPIC.into(),
+                addr_word(5),
+                addr_word(1),
+                addr_word(2),
+            ],
+            vec![word(7), word(0)],
+        ));
+
+        assert_eq!(transfers.len(), 1);
+        let t = &transfers[0];
+        assert_eq!(t.standard, TokenStandard::Erc1155);
+        assert_eq!(t.operator, Some(Address::from([5; 20])));
+        assert_eq!(t.token_id, Some(Quantity::from([7])));
+        assert_eq!(t.amount, Quantity::default());
+    }
+
+    #[test]
+    fn test_erc1155_batch() {
+        let transfers = TokenTransfer::from_log(&log(
+            vec![
+                TRANSFER_BATCH_TOPIC.into(),
+                addr_word(5),
+                addr_word(1),
+                addr_word(2),
+            ],
+            vec![
+                word(64),
+                word(160),
+                word(2),
+                word(7),
+                word(8),
+                word(2),
+                word(100),
+                word(200),
+            ],
+        ));
+
+        let decoded = transfers
+            .iter()
+            .map(|t| (t.batch_index, t.token_id.clone().unwrap(), t.amount.clone()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            decoded,
+            vec![
+                (Some(0), Quantity::from([7]), Quantity::from([100])),
+                (Some(1), Quantity::from([8]), Quantity::from([200])),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_erc1155_batch_length_mismatch() {
+        let transfers = TokenTransfer::from_log(&log(
+            vec![
+                TRANSFER_BATCH_TOPIC.into(),
+                addr_word(5),
+                addr_word(1),
+                addr_word(2),
+            ],
+            vec![word(64), word(128), word(1), word(7), word(0)],
+        ));
+
+        assert!(transfers.is_empty());
+    }
+
+    #[test]
+    fn test_non_standard() {
+        // dirty address padding
+        assert!(TokenTransfer::from_log(&log(
+            vec![TRANSFER_TOPIC.into(), [0xff; 32].into(), addr_word(2)],
+            vec![word(1)],
+        ))
+        .is_empty());
+
+        // data not word aligned
+        let mut unaligned = log(
+            vec![TRANSFER_TOPIC.into(), addr_word(1), addr_word(2)],
+            vec![word(1)],
+        );
+        unaligned.data = unaligned.data[1..].into();
+        assert!(TokenTransfer::from_log(&unaligned).is_empty());
+
+        // unrelated event
+        assert!(TokenTransfer::from_log(&log(vec![word(1)], vec![])).is_empty());
+
+        // anonymous event
+        assert!(TokenTransfer::from_log(&log(vec![], vec![])).is_empty());
+    }
+}
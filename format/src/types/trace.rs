@@ -0,0 +1,194 @@
+use super::{Address, Data, Hash, Quantity, TraceCallType, TraceType};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Trace {
+    pub action: TraceAction,
+    pub block_hash: Hash,
+    pub block_number: u64,
+    pub result: Option<TraceResult>,
+    pub subtraces: u64,
+    pub trace_address: Box<[u64]>,
+    pub transaction_hash: Option<Hash>,
+    pub transaction_position: Option<u64>,
+    #[serde(rename = "type")]
+    pub kind: TraceType,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TraceAction {
+    pub call_type: Option<TraceCallType>,
+    pub from: Option<Address>,
+    pub to: Option<Address>,
+    pub gas: Option<Quantity>,
+    pub input: Option<Data>,
+    pub init: Option<Data>,
+    pub value: Option<Quantity>,
+    pub address: Option<Address>,
+    pub refund_address: Option<Address>,
+    pub balance: Option<Quantity>,
+    pub author: Option<Address>,
+    pub reward_type: Option<String>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TraceResult {
+    pub gas_used: Option<Quantity>,
+    pub output: Option<Data>,
+    pub address: Option<Address>,
+    pub code: Option<Data>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Trace;
+    use crate::types::{Address, Data, Hash, Quantity, TraceCallType, TraceType};
+    use hex_literal::hex;
+
+    // trace_block
+    const BLOCK_TRACES: &str = r#"[
+        {
+            "action": {
+                "callType": "call",
+                "from": "0x83806d539d4ea1c140489a06660319c9a303f874",
+                "gas": "0x1a1f8",
+                "input": "0x",
+                "to": "0x1c39ba39e4735cb65978d4db400ddd70a72dc750",
+                "value": "0x7a16c911b4d00000"
+            },
+            "blockHash": "0x7eb25504e4c202cf3d62fd585d3e238f592c780cca82dacb2ed3cb5b38883add",
+            "blockNumber": 3068185,
+            "result": {
+                "gasUsed": "0x2982",
+                "output": "0x"
+            },
+            "subtraces": 2,
+            "traceAddress": [],
+            "transactionHash": "0x17104ac9d3312d8c136b7f44d4b8b47852618065ebfa534bd2d3b5ef218ca1f3",
+            "transactionPosition": 2,
+            "type": "call"
+        },
+        {
+            "action": {
+                "from": "0x1c39ba39e4735cb65978d4db400ddd70a72dc750",
+                "gas": "0x13e99",
+                "init": "0x6080604052",
+                "value": "0x0"
+            },
+            "blockHash": "0x7eb25504e4c202cf3d62fd585d3e238f592c780cca82dacb2ed3cb5b38883add",
+            "blockNumber": 3068185,
+            "result": {
+                "address": "0x6090a6e47849629b7245dfa1ca21d94cd15878ef",
+                "code": "0x6080",
+                "gasUsed": "0x8e1c"
+            },
+            "subtraces": 0,
+            "traceAddress": [1],
+            "transactionHash": "0x17104ac9d3312d8c136b7f44d4b8b47852618065ebfa534bd2d3b5ef218ca1f3",
+            "transactionPosition": 2,
+            "type": "create"
+        },
+        {
+            "action": {
+                "callType": "delegatecall",
+                "from": "0x1c39ba39e4735cb65978d4db400ddd70a72dc750",
+                "gas": "0x1000",
+                "input": "0x12345678",
+                "to": "0x6090a6e47849629b7245dfa1ca21d94cd15878ef",
+                "value": "0x0"
+            },
+            "blockHash": "0x7eb25504e4c202cf3d62fd585d3e238f592c780cca82dacb2ed3cb5b38883add",
+            "blockNumber": 3068185,
+            "error": "Reverted",
+            "result": null,
+            "subtraces": 0,
+            "traceAddress": [1, 0],
+            "transactionHash": "0x17104ac9d3312d8c136b7f44d4b8b47852618065ebfa534bd2d3b5ef218ca1f3",
+            "transactionPosition": 2,
+            "type": "call"
+        },
+        {
+            "action": {
+                "author": "0xea674fdde714fd979de3edf0f56aa9716b898ec8",
+                "rewardType": "block",
+                "value": "0x29a2241af62c0000"
+            },
+            "blockHash": "0x7eb25504e4c202cf3d62fd585d3e238f592c780cca82dacb2ed3cb5b38883add",
+            "blockNumber": 3068185,
+            "result": null,
+            "subtraces": 0,
+            "traceAddress": [],
+            "type": "reward"
+        }
+    ]"#;
+
+    #[test]
+    fn test_de_trace_block() {
+        let traces: Vec<Trace> = serde_json::from_str(BLOCK_TRACES).unwrap();
+        assert_eq!(traces.len(), 4);
+
+        let call = &traces[0];
+        assert_eq!(call.kind, TraceType::Call);
+        assert_eq!(call.action.call_type, Some(TraceCallType::Call));
+        assert_eq!(
+            call.action.to,
+            Some(Address::from(hex!(
+                "1c39ba39e4735cb65978d4db400ddd70a72dc750"
+            )))
+        );
+        assert_eq!(
+            call.action.value,
+            Some(Quantity::from(hex!("7a16c911b4d00000")))
+        );
+        assert_eq!(call.block_number, 3068185);
+        assert_eq!(
+            call.block_hash,
+            Hash::from(hex!(
+                "7eb25504e4c202cf3d62fd585d3e238f592c780cca82dacb2ed3cb5b38883add"
+            ))
+        );
+        assert_eq!(call.subtraces, 2);
+        assert!(call.trace_address.is_empty());
+        assert_eq!(call.transaction_position, Some(2));
+        assert_eq!(
+            call.result.as_ref().unwrap().gas_used,
+            Some(Quantity::from(hex!("2982")))
+        );
+
+        let create = &traces[1];
+        assert_eq!(create.kind, TraceType::Create);
+        assert_eq!(create.action.call_type, None);
+        assert_eq!(create.action.init, Some(Data::from(hex!("6080604052"))));
+        assert_eq!(create.trace_address.as_ref(), &[1]);
+        assert_eq!(
+            create.result.as_ref().unwrap().address,
+            Some(Address::from(hex!(
+                "6090a6e47849629b7245dfa1ca21d94cd15878ef"
+            )))
+        );
+
+        let failed = &traces[2];
+        assert_eq!(failed.action.call_type, Some(TraceCallType::DelegateCall));
+        assert_eq!(failed.error.as_deref(), Some("Reverted"));
+        assert_eq!(failed.result, None);
+        assert_eq!(failed.trace_address.as_ref(), &[1, 0]);
+
+        let reward = &traces[3];
+        assert_eq!(reward.kind, TraceType::Reward);
+        assert_eq!(reward.action.reward_type.as_deref(), Some("block"));
+        assert_eq!(reward.transaction_hash, None);
+        assert_eq!(reward.transaction_position, None);
+    }
+
+    #[test]
+    fn test_serde_roundtrip() {
+        let traces: Vec<Trace> = serde_json::from_str(BLOCK_TRACES).unwrap();
+        let json = serde_json::to_string(&traces).unwrap();
+
+        assert_eq!(serde_json::from_str::<Vec<Trace>>(&json).unwrap(), traces);
+    }
+}
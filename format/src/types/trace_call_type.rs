@@ -0,0 +1,94 @@
+use crate::{Error, Result};
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::result::Result as StdResult;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TraceCallType {
+    Call,
+    StaticCall,
+    DelegateCall,
+    CallCode,
+}
+
+impl FromStr for TraceCallType {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "call" => Ok(Self::Call),
+            "staticcall" => Ok(Self::StaticCall),
+            "delegatecall" => Ok(Self::DelegateCall),
+            "callcode" => Ok(Self::CallCode),
+            _ => Err(Error::UnknownTraceCallType(s.to_owned())),
+        }
+    }
+}
+
+impl TraceCallType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Call => "call",
+            Self::StaticCall => "staticcall",
+            Self::DelegateCall => "delegatecall",
+            Self::CallCode => "callcode",
+        }
+    }
+}
+
+struct TraceCallTypeVisitor;
+
+impl<'de> Visitor<'de> for TraceCallTypeVisitor {
+    type Value = TraceCallType;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("trace call type string")
+    }
+
+    fn visit_str<E>(self, value: &str) -> StdResult<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        TraceCallType::from_str(value).map_err(|e| E::custom(e.to_string()))
+    }
+}
+
+impl<'de> Deserialize<'de> for TraceCallType {
+    fn deserialize<D>(deserializer: D) -> StdResult<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(TraceCallTypeVisitor)
+    }
+}
+
+impl Serialize for TraceCallType {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TraceCallType;
+    use serde_test::{assert_de_tokens, assert_tokens, Token};
+
+    #[test]
+    fn test_serde() {
+        assert_tokens(&TraceCallType::Call, &[Token::Str("call")]);
+        assert_tokens(&TraceCallType::StaticCall, &[Token::Str("staticcall")]);
+        assert_tokens(&TraceCallType::DelegateCall, &[Token::Str("delegatecall")]);
+        assert_tokens(&TraceCallType::CallCode, &[Token::Str("callcode")]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_de_unknown() {
+        assert_de_tokens(&TraceCallType::Call, &[Token::Str("CALL")]);
+    }
+}
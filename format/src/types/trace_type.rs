@@ -0,0 +1,94 @@
+use crate::{Error, Result};
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::result::Result as StdResult;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TraceType {
+    Call,
+    Create,
+    Suicide,
+    Reward,
+}
+
+impl FromStr for TraceType {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "call" => Ok(Self::Call),
+            "create" => Ok(Self::Create),
+            "suicide" => Ok(Self::Suicide),
+            "reward" => Ok(Self::Reward),
+            _ => Err(Error::UnknownTraceType(s.to_owned())),
+        }
+    }
+}
+
+impl TraceType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Call => "call",
+            Self::Create => "create",
+            Self::Suicide => "suicide",
+            Self::Reward => "reward",
+        }
+    }
+}
+
+struct TraceTypeVisitor;
+
+impl<'de> Visitor<'de> for TraceTypeVisitor {
+    type Value = TraceType;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("trace type string")
+    }
+
+    fn visit_str<E>(self, value: &str) -> StdResult<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        TraceType::from_str(value).map_err(|e| E::custom(e.to_string()))
+    }
+}
+
+impl<'de> Deserialize<'de> for TraceType {
+    fn deserialize<D>(deserializer: D) -> StdResult<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(TraceTypeVisitor)
+    }
+}
+
+impl Serialize for TraceType {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TraceType;
+    use serde_test::{assert_de_tokens, assert_tokens, Token};
+
+    #[test]
+    fn test_serde() {
+        assert_tokens(&TraceType::Call, &[Token::Str("call")]);
+        assert_tokens(&TraceType::Create, &[Token::Str("create")]);
+        assert_tokens(&TraceType::Suicide, &[Token::Str("suicide")]);
+        assert_tokens(&TraceType::Reward, &[Token::Str("reward")]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_de_unknown() {
+        assert_de_tokens(&TraceType::Call, &[Token::Str("CALL")]);
+    }
+}
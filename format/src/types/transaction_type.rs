@@ -10,6 +10,8 @@ pub enum TransactionType {
     Legacy,
     AccessListType,
     DynamicFee,
+    Blob,
+    SetCode,
 }
 
 impl FromStr for TransactionType {
@@ -20,6 +22,8 @@ impl FromStr for TransactionType {
             "0x0" => Ok(Self::Legacy),
             "0x1" => Ok(Self::AccessListType),
             "0x2" => Ok(Self::DynamicFee),
+            "0x3" => Ok(Self::Blob),
+            "0x4" => Ok(Self::SetCode),
             _ => Err(Error::UnknownTransactionType(s.to_owned())),
         }
     }
@@ -31,6 +35,8 @@ impl TransactionType {
             Self::Legacy => "0x0",
             Self::AccessListType => "0x1",
             Self::DynamicFee => "0x2",
+            Self::Blob => "0x3",
+            Self::SetCode => "0x4",
         }
     }
 }
@@ -80,11 +86,13 @@ mod tests {
         assert_tokens(&TransactionType::Legacy, &[Token::Str("0x0")]);
         assert_tokens(&TransactionType::AccessListType, &[Token::Str("0x1")]);
         assert_tokens(&TransactionType::DynamicFee, &[Token::Str("0x2")]);
+        assert_tokens(&TransactionType::Blob, &[Token::Str("0x3")]);
+        assert_tokens(&TransactionType::SetCode, &[Token::Str("0x4")]);
     }
 
     #[test]
     #[should_panic]
     fn test_de_unknown() {
-        assert_de_tokens(&TransactionType::Legacy, &[Token::Str("0x3")]);
+        assert_de_tokens(&TransactionType::Legacy, &[Token::Str("0x7e")]);
     }
 }
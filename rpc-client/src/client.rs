@@ -0,0 +1,339 @@
+use crate::request::{send_batch, RpcRequest};
+use crate::{Error, Result};
+use futures::{StreamExt, TryStreamExt};
+use serde_json::{json, Value};
+use skar_format::types::{Block, BlockTransactions, Hash, TransactionReceipt};
+use std::ops::Range;
+use std::time::Duration;
+use url::Url;
+
+#[derive(Debug, Clone)]
+pub struct RpcClientConfig {
+    pub http_req_timeout: Duration,
+    /// Maximum number of requests sent in a single JSON-RPC batch.
+    pub max_batch_size: usize,
+    /// Maximum number of batches that are sent at the same time.
+    pub max_concurrent_batches: usize,
+}
+
+impl Default for RpcClientConfig {
+    fn default() -> Self {
+        Self {
+            http_req_timeout: Duration::from_secs(30),
+            max_batch_size: 100,
+            max_concurrent_batches: 4,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RpcClient {
+    http: reqwest::Client,
+    url: Url,
+    max_batch_size: usize,
+    max_concurrent_batches: usize,
+}
+
+impl RpcClient {
+    pub fn new(url: Url, config: RpcClientConfig) -> Result<Self> {
+        let http = reqwest::Client::builder()
+            .timeout(config.http_req_timeout)
+            .build()
+            .map_err(Error::BuildHttpClient)?;
+
+        Ok(Self {
+            http,
+            url,
+            max_batch_size: config.max_batch_size.max(1),
+            max_concurrent_batches: config.max_concurrent_batches.max(1),
+        })
+    }
+
+    /// Fetches the blocks in the range with full transactions and their receipts.
+    ///
+    /// Blocks are requested first and then the receipts of their transactions, both split into
+    /// batches of at most `max_batch_size` requests. Receipts of a block are returned in
+    /// transaction order.
+    pub async fn get_block_range(
+        &self,
+        range: Range<u64>,
+    ) -> Result<Vec<(Block, Vec<TransactionReceipt>)>> {
+        let reqs = range
+            .clone()
+            .map(|num| RpcRequest::new(num, "eth_getBlockByNumber", json!([hex_num(num), true])))
+            .collect::<Vec<_>>();
+
+        let blocks = self
+            .send(&reqs)
+            .await?
+            .into_iter()
+            .zip(range)
+            .map(|(res, num)| {
+                if res.is_null() {
+                    return Err(Error::BlockNotFound(num));
+                }
+
+                let block: Block = serde_json::from_value(res).map_err(Error::ParseResponse)?;
+
+                let got = *block.header.number;
+                if got != num {
+                    return Err(Error::UnexpectedBlockNumber { expected: num, got });
+                }
+
+                Ok(block)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let reqs = blocks
+            .iter()
+            .flat_map(transaction_hashes)
+            .enumerate()
+            .map(|(id, hash)| {
+                RpcRequest::new(id as u64, "eth_getTransactionReceipt", json!([hash]))
+            })
+            .collect::<Vec<_>>();
+
+        let mut receipts = self.send(&reqs).await?.into_iter();
+
+        blocks
+            .into_iter()
+            .map(|block| {
+                let block_receipts = transaction_hashes(&block)
+                    .into_iter()
+                    .zip(receipts.by_ref())
+                    .enumerate()
+                    .map(|(idx, (tx_hash, res))| parse_receipt(res, &block, tx_hash, idx))
+                    .collect::<Result<Vec<_>>>()?;
+
+                Ok((block, block_receipts))
+            })
+            .collect()
+    }
+
+    // Returns the results in request order.
+    async fn send(&self, reqs: &[RpcRequest]) -> Result<Vec<Value>> {
+        let batches = reqs
+            .chunks(self.max_batch_size)
+            .map(|reqs| send_batch(&self.http, &self.url, reqs))
+            .collect::<Vec<_>>();
+
+        let batches = futures::stream::iter(batches)
+            .buffered(self.max_concurrent_batches)
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        Ok(batches.into_iter().flatten().collect())
+    }
+}
+
+fn transaction_hashes(block: &Block) -> Vec<&Hash> {
+    match &block.transactions {
+        BlockTransactions::Full(txs) => txs.iter().map(|tx| &tx.hash).collect(),
+        BlockTransactions::Hashes(hashes) => hashes.iter().collect(),
+    }
+}
+
+// Blocks and receipts are fetched separately, so a reorg in between can return receipts from
+// another branch.
+fn parse_receipt(
+    res: Value,
+    block: &Block,
+    tx_hash: &Hash,
+    transaction_index: usize,
+) -> Result<TransactionReceipt> {
+    let block_number = *block.header.number;
+
+    if res.is_null() {
+        return Err(Error::ReceiptNotFound {
+            block_number,
+            transaction_index,
+        });
+    }
+
+    let receipt: TransactionReceipt = serde_json::from_value(res).map_err(Error::ParseResponse)?;
+
+    if receipt.transaction_hash != *tx_hash || receipt.block_hash != block.header.hash {
+        return Err(Error::ReceiptMismatch {
+            block_number,
+            transaction_index,
+        });
+    }
+
+    Ok(receipt)
+}
+
+fn hex_num(num: u64) -> String {
+    format!("0x{num:x}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RpcClient, RpcClientConfig};
+    use crate::Error;
+    use serde_json::{json, Value};
+    use skar_format::types::{BlockTransactions, Quantity, TokenTransfer, TransactionType};
+    use std::sync::{Arc, Mutex};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::{TcpListener, TcpStream};
+    use url::Url;
+
+    // Responses in the shape geth returns them for two Cancun blocks, without `totalDifficulty`.
+    // The first block has an ERC-20 transfer and a blob transaction, the second a legacy transfer.
+    const BLOCKS: &str = include_str!("../testdata/blocks.json");
+    const RECEIPTS: &str = include_str!("../testdata/receipts.json");
+
+    // JSON-RPC server that answers batches from the given blocks and receipts. Responses are
+    // returned in reverse order so they have to be matched by id. Also returns the sizes of the
+    // received batches.
+    async fn serve(blocks: Vec<Value>, receipts: Vec<Value>) -> (Url, Arc<Mutex<Vec<usize>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap())
+            .parse()
+            .unwrap();
+        let batch_sizes = Arc::new(Mutex::new(Vec::new()));
+
+        tokio::spawn({
+            let batch_sizes = batch_sizes.clone();
+            async move {
+                loop {
+                    let (mut socket, _) = listener.accept().await.unwrap();
+                    let reqs: Vec<Value> =
+                        serde_json::from_slice(&read_body(&mut socket).await).unwrap();
+                    batch_sizes.lock().unwrap().push(reqs.len());
+
+                    let responses = reqs
+                        .iter()
+                        .rev()
+                        .map(|req| {
+                            let param = &req["params"][0];
+                            let result = match req["method"].as_str().unwrap() {
+                                "eth_getBlockByNumber" => {
+                                    blocks.iter().find(|block| block["number"] == *param)
+                                }
+                                "eth_getTransactionReceipt" => receipts
+                                    .iter()
+                                    .find(|receipt| receipt["transactionHash"] == *param),
+                                _ => None,
+                            };
+                            json!({"jsonrpc": "2.0", "id": req["id"], "result": result})
+                        })
+                        .collect::<Vec<_>>();
+
+                    let body = serde_json::to_vec(&responses).unwrap();
+                    let head = format!(
+                        "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n",
+                        body.len()
+                    );
+                    socket.write_all(head.as_bytes()).await.unwrap();
+                    socket.write_all(&body).await.unwrap();
+                }
+            }
+        });
+
+        (url, batch_sizes)
+    }
+
+    async fn read_body(socket: &mut TcpStream) -> Vec<u8> {
+        let mut buf = Vec::new();
+
+        loop {
+            let mut chunk = [0; 4096];
+            let n = socket.read(&mut chunk).await.unwrap();
+            assert!(n > 0, "connection closed before the request was read");
+            buf.extend_from_slice(&chunk[..n]);
+
+            let Some(head_end) = buf.windows(4).position(|w| w == b"\r\n\r\n") else {
+                continue;
+            };
+            let head = String::from_utf8_lossy(&buf[..head_end]).to_lowercase();
+            let len: usize = head
+                .lines()
+                .find_map(|line| line.strip_prefix("content-length:"))
+                .unwrap()
+                .trim()
+                .parse()
+                .unwrap();
+
+            let body = head_end + 4;
+            if buf.len() >= body + len {
+                return buf[body..body + len].to_vec();
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_block_range() {
+        let blocks = serde_json::from_str(BLOCKS).unwrap();
+        let receipts = serde_json::from_str(RECEIPTS).unwrap();
+        let (url, batch_sizes) = serve(blocks, receipts).await;
+
+        let client = RpcClient::new(
+            url,
+            RpcClientConfig {
+                max_batch_size: 2,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        let range = client.get_block_range(19426600..19426602).await.unwrap();
+
+        let kinds = range
+            .iter()
+            .map(|(block, receipts)| {
+                let BlockTransactions::Full(txs) = &block.transactions else {
+                    panic!("expected full transactions");
+                };
+                assert_eq!(txs.len(), receipts.len());
+                for (tx, receipt) in txs.iter().zip(receipts.iter()) {
+                    assert_eq!(tx.hash, receipt.transaction_hash);
+                    assert_eq!(tx.block_number, block.header.number);
+                }
+                assert_eq!(block.header.total_difficulty, None);
+
+                receipts.iter().map(|r| r.kind).collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            kinds,
+            vec![
+                vec![TransactionType::DynamicFee, TransactionType::Blob],
+                vec![TransactionType::Legacy],
+            ]
+        );
+
+        let transfers = TokenTransfer::from_log(&range[0].1[0].logs[0]);
+        assert_eq!(transfers.len(), 1);
+        assert_eq!(transfers[0].amount, Quantity::from([0x4c, 0x4b, 0x40]));
+
+        // One batch for the blocks, the three receipts are split into two batches.
+        let mut batch_sizes = batch_sizes.lock().unwrap().clone();
+        batch_sizes.sort_unstable();
+        assert_eq!(batch_sizes, vec![1, 2, 2]);
+
+        let err = client
+            .get_block_range(19426600..19426603)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::BlockNotFound(19426602)));
+
+        // The second receipt comes from a block on another branch.
+        let mut receipts: Vec<Value> = serde_json::from_str(RECEIPTS).unwrap();
+        receipts[1]["blockHash"] = json!(format!("0x{}", "ab".repeat(32)));
+        let (url, _) = serve(serde_json::from_str(BLOCKS).unwrap(), receipts).await;
+
+        let err = RpcClient::new(url, RpcClientConfig::default())
+            .unwrap()
+            .get_block_range(19426600..19426602)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            Error::ReceiptMismatch {
+                block_number: 19426600,
+                transaction_index: 1
+            }
+        ));
+    }
+}
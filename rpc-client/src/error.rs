@@ -0,0 +1,32 @@
+use std::result::Result as StdResult;
+use thiserror::Error as ThisError;
+
+#[derive(Debug, ThisError)]
+pub enum Error {
+    #[error("Failed to build HTTP client:\n{0}")]
+    BuildHttpClient(reqwest::Error),
+    #[error("HTTP request failed:\n{0}")]
+    HttpRequest(reqwest::Error),
+    #[error("Failed to parse RPC response:\n{0}")]
+    ParseResponse(serde_json::Error),
+    #[error("RPC error. Code: {code}, message: {message}")]
+    Rpc { code: i64, message: String },
+    #[error("Response for request {0} is missing from the batch response.")]
+    MissingResponse(u64),
+    #[error("Block {0} not found.")]
+    BlockNotFound(u64),
+    #[error("Unexpected block number. Expected {expected} got {got}.")]
+    UnexpectedBlockNumber { expected: u64, got: u64 },
+    #[error("Receipt of transaction {transaction_index} in block {block_number} not found.")]
+    ReceiptNotFound {
+        block_number: u64,
+        transaction_index: usize,
+    },
+    #[error("Receipt of transaction {transaction_index} in block {block_number} belongs to a different block or transaction. The chain might have reorganized.")]
+    ReceiptMismatch {
+        block_number: u64,
+        transaction_index: usize,
+    },
+}
+
+pub type Result<T> = StdResult<T, Error>;
@@ -1 +1,8 @@
+mod client;
+mod error;
 mod request;
+mod stream;
+
+pub use client::{RpcClient, RpcClientConfig};
+pub use error::{Error, Result};
+pub use stream::StreamConfig;
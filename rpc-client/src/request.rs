@@ -1 +1,180 @@
+use crate::{Error, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
 
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct RpcRequest {
+    jsonrpc: &'static str,
+    id: u64,
+    method: &'static str,
+    params: Value,
+}
+
+impl RpcRequest {
+    pub(crate) fn new(id: u64, method: &'static str, params: Value) -> Self {
+        Self {
+            jsonrpc: "2.0",
+            id,
+            method,
+            params,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct RpcResponse {
+    id: Option<u64>,
+    #[serde(default)]
+    result: Value,
+    error: Option<RpcError>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RpcError {
+    code: i64,
+    message: String,
+}
+
+impl From<RpcError> for Error {
+    fn from(err: RpcError) -> Self {
+        Error::Rpc {
+            code: err.code,
+            message: err.message,
+        }
+    }
+}
+
+/// Sends the requests as a single JSON-RPC batch.
+///
+/// Returns the results in the order of the requests, a missing result is returned as `null`.
+/// Request ids have to be unique within the batch.
+pub(crate) async fn send_batch(
+    http: &reqwest::Client,
+    url: &url::Url,
+    reqs: &[RpcRequest],
+) -> Result<Vec<Value>> {
+    if reqs.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let body = http
+        .post(url.clone())
+        .json(reqs)
+        .send()
+        .await
+        .and_then(|res| res.error_for_status())
+        .map_err(Error::HttpRequest)?
+        .bytes()
+        .await
+        .map_err(Error::HttpRequest)?;
+
+    parse_batch_response(&body, reqs)
+}
+
+fn parse_batch_response(body: &[u8], reqs: &[RpcRequest]) -> Result<Vec<Value>> {
+    // Nodes answer with a single error object instead of an array if the whole batch is rejected.
+    let responses: Vec<RpcResponse> = match serde_json::from_slice(body) {
+        Ok(responses) => responses,
+        Err(e) => {
+            return Err(match serde_json::from_slice::<RpcResponse>(body) {
+                Ok(RpcResponse {
+                    error: Some(err), ..
+                }) => err.into(),
+                _ => Error::ParseResponse(e),
+            })
+        }
+    };
+
+    let mut responses = responses
+        .into_iter()
+        .filter_map(|res| Some((res.id?, res)))
+        .collect::<HashMap<_, _>>();
+
+    reqs.iter()
+        .map(|req| {
+            let res = responses
+                .remove(&req.id)
+                .ok_or(Error::MissingResponse(req.id))?;
+
+            match res.error {
+                Some(err) => Err(err.into()),
+                None => Ok(res.result),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_batch_response, RpcRequest};
+    use crate::Error;
+    use serde_json::{json, Value};
+
+    fn reqs(ids: &[u64]) -> Vec<RpcRequest> {
+        ids.iter()
+            .map(|id| RpcRequest::new(*id, "eth_blockNumber", json!([])))
+            .collect()
+    }
+
+    #[test]
+    fn test_out_of_order() {
+        let body = br#"[
+            {"jsonrpc":"2.0","id":2,"result":"0x2"},
+            {"jsonrpc":"2.0","id":0,"result":"0x0"},
+            {"jsonrpc":"2.0","id":1,"result":null}
+        ]"#;
+
+        let results = parse_batch_response(body, &reqs(&[0, 1, 2])).unwrap();
+
+        assert_eq!(results, vec![json!("0x0"), Value::Null, json!("0x2")]);
+    }
+
+    #[test]
+    fn test_missing_response() {
+        let body = br#"[{"jsonrpc":"2.0","id":0,"result":"0x0"}]"#;
+
+        let err = parse_batch_response(body, &reqs(&[0, 1])).unwrap_err();
+
+        assert!(matches!(err, Error::MissingResponse(1)));
+    }
+
+    #[test]
+    fn test_request_error() {
+        let body = br#"[
+            {"jsonrpc":"2.0","id":0,"result":"0x0"},
+            {"jsonrpc":"2.0","id":1,"error":{"code":-32000,"message":"header not found"}}
+        ]"#;
+
+        let err = parse_batch_response(body, &reqs(&[0, 1])).unwrap_err();
+
+        assert!(
+            matches!(err, Error::Rpc { code: -32000, message } if message == "header not found")
+        );
+    }
+
+    #[test]
+    fn test_batch_error() {
+        let body =
+            br#"{"jsonrpc":"2.0","id":null,"error":{"code":-32600,"message":"batch too large"}}"#;
+
+        let err = parse_batch_response(body, &reqs(&[0, 1])).unwrap_err();
+
+        assert!(
+            matches!(err, Error::Rpc { code: -32600, message } if message == "batch too large")
+        );
+    }
+
+    #[test]
+    fn test_invalid_response() {
+        assert!(matches!(
+            parse_batch_response(b"<html>bad gateway</html>", &reqs(&[0])).unwrap_err(),
+            Error::ParseResponse(_)
+        ));
+        assert!(matches!(
+            parse_batch_response(br#"{"jsonrpc":"2.0","id":0,"result":"0x0"}"#, &reqs(&[0]))
+                .unwrap_err(),
+            Error::ParseResponse(_)
+        ));
+    }
+}
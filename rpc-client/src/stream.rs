@@ -0,0 +1,522 @@
+use crate::{Result, RpcClient};
+use futures::future::{BoxFuture, FutureExt};
+use futures::stream::{FuturesOrdered, Stream, StreamExt};
+use skar_format::types::{Block, TransactionReceipt};
+use std::future::Future;
+use std::ops::Range;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+#[derive(Debug, Clone)]
+pub struct StreamConfig {
+    /// Maximum number of chunks that are fetched at the same time.
+    pub concurrency: usize,
+    pub initial_chunk_size: u64,
+    pub min_chunk_size: u64,
+    pub max_chunk_size: u64,
+    /// Response time the chunk size is adjusted towards.
+    pub target_latency: Duration,
+    /// Number of times a failed chunk is retried before the stream returns the error. Each retry
+    /// splits the failed range in half as long as the halves aren't below `min_chunk_size`.
+    pub max_retries: usize,
+    /// Wait time before a retry, multiplied by the number of the attempt.
+    pub retry_backoff: Duration,
+    /// Number of fetched blocks that are buffered if the consumer falls behind.
+    pub buffer_size: usize,
+}
+
+impl Default for StreamConfig {
+    fn default() -> Self {
+        Self {
+            concurrency: 4,
+            initial_chunk_size: 10,
+            min_chunk_size: 1,
+            max_chunk_size: 1000,
+            target_latency: Duration::from_secs(1),
+            max_retries: 5,
+            retry_backoff: Duration::from_millis(500),
+            buffer_size: 1000,
+        }
+    }
+}
+
+impl RpcClient {
+    /// Streams the blocks in the range together with their receipts in block order.
+    ///
+    /// The range is split into chunks that are fetched concurrently. The chunk size grows or
+    /// shrinks based on response latency and errors. The stream ends after the first error that
+    /// persists through all retries.
+    ///
+    /// Fetching happens in a spawned task, so this has to be called inside a tokio runtime.
+    /// Dropping the stream stops the task.
+    pub fn stream_blocks(
+        &self,
+        range: Range<u64>,
+        config: StreamConfig,
+    ) -> impl Stream<Item = Result<(Block, Vec<TransactionReceipt>)>> + Send + 'static {
+        let client = self.clone();
+
+        stream_chunks(range, config, move |range| {
+            let client = client.clone();
+            async move { client.get_block_range(range).await }
+        })
+    }
+}
+
+pub(crate) fn stream_chunks<T, F, Fut>(
+    range: Range<u64>,
+    config: StreamConfig,
+    fetch: F,
+) -> impl Stream<Item = Result<T>> + Send + 'static
+where
+    T: Send + 'static,
+    F: Fn(Range<u64>) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<Vec<T>>> + Send + 'static,
+{
+    let (tx, rx) = mpsc::channel(config.buffer_size.max(1));
+    let fetch = Arc::new(fetch);
+
+    let driver = tokio::spawn(async move {
+        let sizer = Arc::new(Mutex::new(ChunkSizer::new(&config)));
+        let mut next_block = range.start;
+        let mut chunks = FuturesOrdered::new();
+
+        loop {
+            while chunks.len() < config.concurrency.max(1) && next_block < range.end {
+                let chunk_size = sizer.lock().unwrap().chunk_size;
+                let end = next_block.saturating_add(chunk_size).min(range.end);
+
+                // Chunks run in their own tasks so they make progress and their latency is
+                // measured correctly while this task waits for the consumer.
+                chunks.push_back(ChunkTask(tokio::spawn(fetch_chunk(
+                    fetch.clone(),
+                    next_block..end,
+                    sizer.clone(),
+                    config.clone(),
+                    0,
+                ))));
+
+                next_block = end;
+            }
+
+            let res = tokio::select! {
+                res = chunks.next() => res,
+                _ = tx.closed() => return,
+            };
+
+            match res {
+                Some(Ok(items)) => {
+                    for item in items {
+                        if tx.send(Ok(item)).await.is_err() {
+                            return;
+                        }
+                    }
+                }
+                Some(Err(e)) => {
+                    tx.send(Err(e)).await.ok();
+                    return;
+                }
+                None => return,
+            }
+        }
+    });
+
+    futures::stream::unfold((rx, driver), |(mut rx, driver)| async move {
+        if let Some(item) = rx.recv().await {
+            return Some((item, (rx, driver)));
+        }
+
+        // Surface a panic in fetching to the consumer instead of silently ending the stream.
+        if let Err(e) = driver.await {
+            if e.is_panic() {
+                std::panic::resume_unwind(e.into_panic());
+            }
+        }
+
+        None
+    })
+}
+
+// Aborts the chunk task if the stream is dropped before the chunk is consumed.
+struct ChunkTask<T>(JoinHandle<Result<Vec<T>>>);
+
+impl<T> Future for ChunkTask<T> {
+    type Output = Result<Vec<T>>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.0)
+            .poll(cx)
+            .map(|res| res.unwrap_or_else(|e| std::panic::resume_unwind(e.into_panic())))
+    }
+}
+
+impl<T> Drop for ChunkTask<T> {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+// A failed range is split in half for the next attempt, in case it failed because the response
+// was too large or too slow. The halves are fetched one after the other so a chunk never has more
+// than one request in flight and retries stay within the configured concurrency.
+fn fetch_chunk<T, F, Fut>(
+    fetch: Arc<F>,
+    range: Range<u64>,
+    sizer: Arc<Mutex<ChunkSizer>>,
+    config: StreamConfig,
+    attempt: usize,
+) -> BoxFuture<'static, Result<Vec<T>>>
+where
+    T: Send + 'static,
+    F: Fn(Range<u64>) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<Vec<T>>> + Send + 'static,
+{
+    async move {
+        let start = Instant::now();
+
+        let e = match fetch(range.clone()).await {
+            Ok(items) => {
+                sizer
+                    .lock()
+                    .unwrap()
+                    .record_success(range.end - range.start, start.elapsed());
+                return Ok(items);
+            }
+            Err(e) => e,
+        };
+
+        sizer.lock().unwrap().record_error();
+
+        if attempt == config.max_retries {
+            return Err(e);
+        }
+
+        tokio::time::sleep(config.retry_backoff * (attempt + 1) as u32).await;
+
+        let min_chunk_size = sizer.lock().unwrap().min_chunk_size;
+        let len = range.end - range.start;
+        if len / 2 < min_chunk_size {
+            return fetch_chunk(fetch, range, sizer, config, attempt + 1).await;
+        }
+
+        let mid = range.start + len / 2;
+        let mut items = fetch_chunk(
+            fetch.clone(),
+            range.start..mid,
+            sizer.clone(),
+            config.clone(),
+            attempt + 1,
+        )
+        .await?;
+        items.extend(fetch_chunk(fetch, mid..range.end, sizer, config, attempt + 1).await?);
+
+        Ok(items)
+    }
+    .boxed()
+}
+
+#[derive(Debug)]
+struct ChunkSizer {
+    chunk_size: u64,
+    min_chunk_size: u64,
+    max_chunk_size: u64,
+    target_latency: Duration,
+}
+
+impl ChunkSizer {
+    fn new(config: &StreamConfig) -> Self {
+        let min_chunk_size = config.min_chunk_size.max(1);
+        let max_chunk_size = config.max_chunk_size.max(min_chunk_size);
+
+        Self {
+            chunk_size: config
+                .initial_chunk_size
+                .clamp(min_chunk_size, max_chunk_size),
+            min_chunk_size,
+            max_chunk_size,
+            target_latency: config.target_latency,
+        }
+    }
+
+    // Scales the chunk size towards the size that would have hit the target latency, changing it
+    // at most by a factor of two per response so single outliers don't swing it too far.
+    fn record_success(&mut self, num_blocks: u64, latency: Duration) {
+        let latency = latency.as_secs_f64().max(f64::EPSILON);
+        let ideal = num_blocks as f64 * self.target_latency.as_secs_f64() / latency;
+        let ideal = ideal.clamp(self.chunk_size as f64 / 2.0, self.chunk_size as f64 * 2.0);
+
+        self.chunk_size = (ideal as u64).clamp(self.min_chunk_size, self.max_chunk_size);
+    }
+
+    fn record_error(&mut self) {
+        self.chunk_size = (self.chunk_size / 2).max(self.min_chunk_size);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{stream_chunks, ChunkSizer, StreamConfig};
+    use crate::Error;
+    use futures::future::{BoxFuture, FutureExt};
+    use futures::StreamExt;
+    use std::ops::Range;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    fn config() -> StreamConfig {
+        StreamConfig {
+            concurrency: 4,
+            initial_chunk_size: 3,
+            retry_backoff: Duration::from_millis(1),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_chunk_sizer() {
+        let mut sizer = ChunkSizer::new(&StreamConfig {
+            initial_chunk_size: 100,
+            min_chunk_size: 10,
+            max_chunk_size: 300,
+            target_latency: Duration::from_secs(1),
+            ..Default::default()
+        });
+
+        sizer.record_success(100, Duration::from_millis(800));
+        assert_eq!(sizer.chunk_size, 125);
+
+        sizer.record_success(125, Duration::from_millis(10));
+        assert_eq!(sizer.chunk_size, 250);
+
+        sizer.record_success(250, Duration::from_millis(10));
+        assert_eq!(sizer.chunk_size, 300);
+
+        sizer.record_success(300, Duration::from_secs(60));
+        assert_eq!(sizer.chunk_size, 150);
+
+        for _ in 0..10 {
+            sizer.record_error();
+        }
+        assert_eq!(sizer.chunk_size, 10);
+    }
+
+    #[test]
+    fn test_chunk_sizer_invalid_config() {
+        let sizer = ChunkSizer::new(&StreamConfig {
+            initial_chunk_size: 0,
+            min_chunk_size: 0,
+            max_chunk_size: 0,
+            ..Default::default()
+        });
+
+        assert_eq!(sizer.chunk_size, 1);
+    }
+
+    #[tokio::test]
+    async fn test_ordered() {
+        // Later chunks finish first.
+        let stream = stream_chunks(0..50, config(), |range: Range<u64>| async move {
+            tokio::time::sleep(Duration::from_millis(50 - range.start)).await;
+            Ok(range.collect::<Vec<_>>())
+        });
+
+        let items = stream.map(Result::unwrap).collect::<Vec<_>>().await;
+
+        assert_eq!(items, (0..50).collect::<Vec<_>>());
+    }
+
+    #[tokio::test]
+    async fn test_retry() {
+        let calls = Arc::new(AtomicUsize::new(0));
+
+        let stream = stream_chunks(0..20, config(), {
+            let calls = calls.clone();
+            move |range: Range<u64>| {
+                let fail = range.contains(&7) && calls.fetch_add(1, Ordering::SeqCst) < 2;
+                async move {
+                    if fail {
+                        return Err(Error::BlockNotFound(7));
+                    }
+                    Ok(range.collect::<Vec<_>>())
+                }
+            }
+        });
+
+        let items = stream.map(Result::unwrap).collect::<Vec<_>>().await;
+
+        assert_eq!(items, (0..20).collect::<Vec<_>>());
+    }
+
+    type FetchFuture = BoxFuture<'static, crate::Result<Vec<u64>>>;
+    type RecordedRanges = Arc<Mutex<Vec<Range<u64>>>>;
+
+    // Fetches that fail for every range larger than two blocks. Records the requested ranges
+    // and the highest number of requests in flight.
+    fn fetch_small_ranges() -> (
+        impl Fn(Range<u64>) -> FetchFuture + Send + Sync + 'static,
+        RecordedRanges,
+        Arc<AtomicUsize>,
+    ) {
+        let ranges = Arc::new(Mutex::new(Vec::new()));
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let max_in_flight = Arc::new(AtomicUsize::new(0));
+
+        let fetch = {
+            let ranges = ranges.clone();
+            let max_in_flight = max_in_flight.clone();
+            move |range: Range<u64>| {
+                ranges.lock().unwrap().push(range.clone());
+                let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                max_in_flight.fetch_max(now, Ordering::SeqCst);
+                let in_flight = in_flight.clone();
+
+                async move {
+                    tokio::time::sleep(Duration::from_millis(2)).await;
+                    in_flight.fetch_sub(1, Ordering::SeqCst);
+
+                    if range.end - range.start > 2 {
+                        return Err(Error::BlockNotFound(range.start));
+                    }
+                    Ok(range.collect::<Vec<_>>())
+                }
+                .boxed()
+            }
+        };
+
+        (fetch, ranges, max_in_flight)
+    }
+
+    #[tokio::test]
+    async fn test_split_on_error() {
+        let (fetch, ranges, max_in_flight) = fetch_small_ranges();
+
+        let stream = stream_chunks(
+            0..20,
+            StreamConfig {
+                initial_chunk_size: 8,
+                ..config()
+            },
+            fetch,
+        );
+
+        let items = stream.map(Result::unwrap).collect::<Vec<_>>().await;
+
+        assert_eq!(items, (0..20).collect::<Vec<_>>());
+        let ranges = ranges.lock().unwrap();
+        assert!(ranges.contains(&(0..8)));
+        assert!(ranges.contains(&(0..4)));
+        assert!(ranges.contains(&(0..2)));
+        // The failed range is split instead of being retried at the same size.
+        assert_eq!(ranges.iter().filter(|r| **r == (0..8)).count(), 1);
+        // Retries don't exceed the configured concurrency.
+        assert!(max_in_flight.load(Ordering::SeqCst) <= config().concurrency);
+    }
+
+    #[tokio::test]
+    async fn test_split_min_chunk_size() {
+        let (fetch, ranges, _) = fetch_small_ranges();
+
+        let stream = stream_chunks(
+            0..16,
+            StreamConfig {
+                initial_chunk_size: 8,
+                min_chunk_size: 4,
+                ..config()
+            },
+            fetch,
+        );
+
+        let items = stream.collect::<Vec<_>>().await;
+
+        assert!(matches!(items.as_slice(), [Err(Error::BlockNotFound(_))]));
+        assert!(ranges.lock().unwrap().iter().all(|r| r.end - r.start >= 4));
+    }
+
+    #[tokio::test]
+    async fn test_drop_stops_fetching() {
+        struct InFlight(Arc<AtomicUsize>);
+
+        impl Drop for InFlight {
+            fn drop(&mut self) {
+                self.0.fetch_sub(1, Ordering::SeqCst);
+            }
+        }
+
+        let in_flight = Arc::new(AtomicUsize::new(0));
+
+        // Everything after the first three blocks hangs.
+        let mut stream = Box::pin(stream_chunks(
+            0..100,
+            StreamConfig {
+                initial_chunk_size: 1,
+                max_chunk_size: 1,
+                ..config()
+            },
+            {
+                let in_flight = in_flight.clone();
+                move |range: Range<u64>| {
+                    in_flight.fetch_add(1, Ordering::SeqCst);
+                    let guard = InFlight(in_flight.clone());
+                    async move {
+                        let _guard = guard;
+                        if range.start >= 3 {
+                            tokio::time::sleep(Duration::from_secs(3600)).await;
+                        }
+                        Ok(range.collect::<Vec<_>>())
+                    }
+                }
+            },
+        ));
+
+        for i in 0..3 {
+            assert_eq!(stream.next().await.unwrap().unwrap(), i);
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        assert!(in_flight.load(Ordering::SeqCst) > 0);
+
+        drop(stream);
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        assert_eq!(in_flight.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "fetch panicked")]
+    async fn test_panic() {
+        let stream = stream_chunks(0..20, config(), |range: Range<u64>| async move {
+            if range.contains(&7) {
+                panic!("fetch panicked");
+            }
+            Ok(range.collect::<Vec<_>>())
+        });
+
+        stream.collect::<Vec<_>>().await;
+    }
+
+    #[tokio::test]
+    async fn test_error_ends_stream() {
+        let stream = stream_chunks(0..20, config(), |range: Range<u64>| async move {
+            if range.contains(&7) {
+                return Err(Error::BlockNotFound(7));
+            }
+            Ok(range.collect::<Vec<_>>())
+        });
+
+        let items = stream.collect::<Vec<_>>().await;
+        let (last, items) = items.split_last().unwrap();
+
+        assert!(matches!(last, Err(Error::BlockNotFound(7))));
+        assert_eq!(
+            items
+                .iter()
+                .map(|r| *r.as_ref().unwrap())
+                .collect::<Vec<_>>(),
+            (0..6).collect::<Vec<_>>()
+        );
+    }
+}